@@ -1,8 +1,152 @@
+# Other users cannot create directories under XDG_RUNTIME_DIR, unlike a shared TMPDIR.
+__croque_async_dir="${XDG_RUNTIME_DIR:-${TMPDIR:-/tmp}}/croque-$UID"
+mkdir -p -m 700 "$__croque_async_dir" 2>/dev/null
+if [[ -L "$__croque_async_dir" || ! -O "$__croque_async_dir" ]] || ! chmod 700 "$__croque_async_dir" 2>/dev/null; then
+  # Someone else created the directory first, so results written there could be tampered with.
+  __croque_async_dir="$(mktemp -d "${TMPDIR:-/tmp}/croque-$UID.XXXXXX")"
+fi
+__croque_async_generation=0
+__croque_prompt_active=0
+__croque_prompt_rows=0
+__croque_redrawing=0
+
+croque::prepare-async::callback() {
+  [[ -n "$__croque_winch_trap" ]] && eval "$__croque_winch_trap"
+
+  local source file updated=0
+  for source in git gh glab jj hg; do
+    file="$__croque_async_dir/$$.$__croque_async_generation.$source"
+    [[ " $__croque_async_loaded " != *" $source "* && -f "$file" ]] || continue
+    printf -v "__croque_${source}_info" '%s' "$(<"$file")"
+    __croque_async_loaded+=" $source"
+    updated=1
+  done
+
+  (( updated )) || return
+  croque::render
+  # Readline keeps drawing the prompt it started with, so ask the terminal for a status report whose reply runs croque::redraw.
+  (( __croque_prompt_active )) && printf '\e[5n'
+}
+
+croque::prepare-async() {
+  local source="$1"
+  local file="$__croque_async_dir/$$.$__croque_async_generation.$source"
+  local pid="$$"
+  ( (
+    croque prepare "$source" >"$file.tmp" 2>/dev/null && mv -f "$file.tmp" "$file" && kill -WINCH "$pid"
+  ) & ) 2>/dev/null
+}
+
+croque::prepare() {
+  (( __croque_async_generation++ ))
+  __croque_async_loaded=""
+  local stale=("$__croque_async_dir/$$".*)
+  [[ -e "${stale[0]}" ]] && rm -f "${stale[@]}"
+
+  croque::prepare-async git
+  command -v gh >/dev/null && croque::prepare-async gh
+  command -v glab >/dev/null && croque::prepare-async glab
+  command -v jj >/dev/null && croque::prepare-async jj
+  command -v hg >/dev/null && croque::prepare-async hg
+}
+
+croque::render() {
+  PS1='${__croque_prompt_active:0:$((__croque_prompt_active=1,0))}'"$(croque prompt --exit-status="$__croque_exit_status" --jobs="$__croque_jobs" --duration="$__croque_duration" --width="$COLUMNS" --data.git="$__croque_git_info" --data.gh="$__croque_gh_info" --data.glab="$__croque_glab_info" --data.jj="$__croque_jj_info" --data.hg="$__croque_hg_info" bash)"
+}
+
+croque::redraw() {
+  # Accepting an empty line makes readline draw the new prompt. The redraw is skipped once something has been typed, as that text would be lost.
+  if (( __croque_prompt_active )) && [[ -z "$READLINE_LINE" ]]; then
+    __croque_redrawing=1
+    # No command runs for the empty line, so the other PROMPT_COMMAND entries are left out until croque::precmd restores them.
+    __croque_prompt_command=("${PROMPT_COMMAND[@]}")
+    PROMPT_COMMAND=(croque::precmd)
+    bind '"\e[99~": accept-line'
+  else
+    bind '"\e[99~": redraw-current-line'
+  fi
+}
+
+croque::prompt-rows() {
+  # The rows PS1 takes up, including the lines that wrap at the terminal width. Characters are counted as one column each, as bash cannot measure wide ones.
+  local rows=0 columns="${COLUMNS:-80}" prompt line width
+  if (( BASH_VERSINFO[0] * 100 + BASH_VERSINFO[1] >= 404 )); then
+    local active="$__croque_prompt_active"
+    prompt="${PS1@P}"
+    __croque_prompt_active="$active"
+    while [[ "$prompt" == *$'\001'*$'\002'* ]]; do
+      prompt="${prompt%%$'\001'*}${prompt#*$'\002'}"
+    done
+    while IFS= read -r line; do
+      width="${#line}"
+      (( rows += width > 0 ? (width - 1) / columns + 1 : 1 ))
+    done <<<"$prompt"
+  else
+    local newlines="${PS1//[!$'\n']/}"
+    rows="$(( ${#newlines} + 1 ))"
+  fi
+  __croque_prompt_rows="$rows"
+}
+
+croque::timestamp() {
+  # Microseconds since the epoch, falling back to the second resolution of bash < 5.0.
+  local now="${EPOCHREALTIME//[!0-9]/}"
+  __croque_timestamp="$(( ${now:-SECONDS * 1000000} ))"
+}
+
+croque::duration() {
+  croque::timestamp
+  local elapsed="$(( __croque_timestamp - ${__croque_start:-$__croque_timestamp} ))"
+  printf -v __croque_duration '%d.%06d' "$(( elapsed / 1000000 ))" "$(( elapsed % 1000000 ))"
+  unset __croque_start
+}
+
 croque::precmd() {
   local exit_status="$?"
-  local jobs="$(jobs | wc -l)"
-  local duration="0"
-  PS1="$(croque prompt --exit-status="$exit_status" --jobs="$jobs" --duration="$duration" --width="$COLUMNS" --data.gh="" --data.glab="" bash)"
+  __croque_prompt_active=0
+
+  if (( __croque_redrawing )); then
+    # Erase the previous prompt, which the empty line accepted by croque::redraw left behind.
+    __croque_redrawing=0
+    PROMPT_COMMAND=("${__croque_prompt_command[@]}")
+    unset __croque_prompt_command
+    printf '\e[%dA\r\e[J' "$__croque_prompt_rows"
+    croque::prompt-rows
+    return
+  fi
+
+  __croque_exit_status="$exit_status"
+  __croque_jobs="$(jobs | wc -l)"
+  croque::duration
+
+  if [[ "$PWD" != "$__croque_pwd" ]]; then
+    __croque_pwd="$PWD"
+    unset __croque_git_info __croque_gh_info __croque_glab_info __croque_jj_info __croque_hg_info
+  fi
+
+  croque::prepare
+  croque::render
+  PS2='${__croque_prompt_active:0:$((__croque_prompt_active=0,0))}'"$(croque prompt --continuation --exit-status="$__croque_exit_status" --jobs="$__croque_jobs" --duration="$__croque_duration" --width="$COLUMNS" --data.git="$__croque_git_info" --data.gh="$__croque_gh_info" --data.glab="$__croque_glab_info" --data.jj="$__croque_jj_info" --data.hg="$__croque_hg_info" bash)"
+  croque::prompt-rows
 }
 
-PROMPT_COMMAND=croque::precmd
+# The reply to the status report requested by croque::prepare-async::callback, followed by the key bound by croque::redraw.
+for __croque_keymap in emacs vi-insert vi-command; do
+  bind -m "$__croque_keymap" -x '"\e[98~": croque::redraw' 2>/dev/null
+  bind -m "$__croque_keymap" '"\e[0n": "\e[98~\e[99~"' 2>/dev/null
+done
+unset __croque_keymap
+
+# Keep running the WINCH trap that was set before, which an earlier init may already have saved.
+__croque_trap="$(trap -p WINCH)"
+__croque_trap="${__croque_trap#trap -- }"
+eval "__croque_trap=${__croque_trap% SIGWINCH}"
+[[ "$__croque_trap" != croque::prepare-async::callback ]] && __croque_winch_trap="$__croque_trap"
+unset __croque_trap
+
+# Bash defers traps while reading input except for signals readline handles itself.
+trap croque::prepare-async::callback WINCH
+
+# PS0 is expanded once per command line in the current shell, unlike a DEBUG trap which also fires for PROMPT_COMMAND and subshells.
+PS0="${PS0}"'${__croque_prompt_active:0:$((__croque_prompt_active=0,__croque_start=${EPOCHREALTIME:+${EPOCHREALTIME//[!0-9]/}+0*}SECONDS*1000000,0))}'
+PROMPT_COMMAND="croque::precmd${PROMPT_COMMAND:+;$PROMPT_COMMAND}"
@@ -1,7 +1,86 @@
+set -g __croque_async_dir (command mktemp -d)
+
+function __croque_prepare_async --argument-names source
+  set -l pid_var __croque_{$source}_pid
+  set -q $pid_var; and command kill $$pid_var 2>/dev/null
+
+  # Background jobs cannot set variables in this shell, so they write a file and signal it instead.
+  set -l file $__croque_async_dir/$source
+  command rm -f $file
+  set file (string escape -- $file)
+  command (status fish-path) --no-config --command "croque prepare $source >$file.tmp; and command mv -f $file.tmp $file; and command kill -USR1 $fish_pid" &
+  set -g $pid_var $last_pid
+  builtin disown
+end
+
+function __croque_prepare_async_callback --on-signal SIGUSR1
+  set -l updated
+  for source in git gh glab jj hg
+    set -l file $__croque_async_dir/$source
+    test -f $file; or continue
+    set -g __croque_{$source}_info (string collect <$file)
+    command rm -f $file
+    set updated 1
+  end
+
+  set -q updated[1]; and set -q __croque_prompt_active; or return
+  set -g __croque_repaint
+  commandline -f repaint
+end
+
+# Results arriving while a command runs are left to the next prompt, which must take the new status and start new jobs.
+function __croque_preexec --on-event fish_preexec
+  set -e __croque_prompt_active
+  set -e __croque_repaint
+end
+
+function __croque_prepare
+  __croque_prepare_async git
+  command -q gh; and __croque_prepare_async gh
+  command -q glab; and __croque_prepare_async glab
+  command -q jj; and __croque_prepare_async jj
+  command -q hg; and __croque_prepare_async hg
+end
+
+function __croque_chpwd --on-variable PWD
+  set -e __croque_git_info
+  set -e __croque_gh_info
+  set -e __croque_glab_info
+  set -e __croque_jj_info
+  set -e __croque_hg_info
+end
+
+function __croque_exit --on-event fish_exit
+  command rm -rf $__croque_async_dir
+end
+
 function fish_prompt
-  croque prompt --exit-status=$status --jobs=(count (jobs -p)) --duration=(math $CMD_DURATION / 1000) --width=$COLUMNS --data.gh="" --data.glab="" fish
+  set -l exit_status $status
+  if set -q __croque_transient
+    croque prompt --transient --exit-status=$__croque_exit_status --jobs=$__croque_jobs --duration=$__croque_duration --width=$COLUMNS --data.git="$__croque_git_info" --data.gh="$__croque_gh_info" --data.glab="$__croque_glab_info" --data.jj="$__croque_jj_info" --data.hg="$__croque_hg_info" fish
+    return
+  end
+
+  # A repaint requested by a finished background job must not start the jobs again.
+  if set -q __croque_repaint
+    set -e __croque_repaint
+  else
+    set -g __croque_exit_status $exit_status
+    set -g __croque_jobs (count (jobs -p))
+    set -g __croque_duration (math $CMD_DURATION / 1000)
+    __croque_prepare
+  end
+  set -g __croque_prompt_active
+
+  croque prompt --exit-status=$__croque_exit_status --jobs=$__croque_jobs --duration=$__croque_duration --width=$COLUMNS --data.git="$__croque_git_info" --data.gh="$__croque_gh_info" --data.glab="$__croque_glab_info" --data.jj="$__croque_jj_info" --data.hg="$__croque_hg_info" fish
 end
 
 function fish_right_prompt
-  croque prompt --right --exit-status=$status --jobs=(count (jobs -p)) --duration=(math $CMD_DURATION / 1000) --width=$COLUMNS --data.gh="" --data.glab="" fish
+  if set -q __croque_transient
+    set -e __croque_transient
+    croque prompt --right --transient --exit-status=$__croque_exit_status --jobs=$__croque_jobs --duration=$__croque_duration --width=$COLUMNS --data.git="$__croque_git_info" --data.gh="$__croque_gh_info" --data.glab="$__croque_glab_info" --data.jj="$__croque_jj_info" --data.hg="$__croque_hg_info" fish
+    return
+  end
+
+  croque prompt --right --exit-status=$__croque_exit_status --jobs=$__croque_jobs --duration=$__croque_duration --width=$COLUMNS --data.git="$__croque_git_info" --data.gh="$__croque_gh_info" --data.glab="$__croque_glab_info" --data.jj="$__croque_jj_info" --data.hg="$__croque_hg_info" fish
 end
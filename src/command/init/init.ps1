@@ -0,0 +1,34 @@
+$global:__croque_last_history_id = -1
+
+function global:prompt {
+  $success = $?
+  $lastExitCode = $global:LASTEXITCODE
+
+  $exitStatus = if ($success) { 0 } elseif ($lastExitCode) { $lastExitCode } else { 1 }
+
+  # Only report the duration once per command, not again for an empty command line.
+  $duration = 0
+  $lastCommand = Get-History -Count 1
+  if ($lastCommand -and $lastCommand.Id -ne $global:__croque_last_history_id) {
+    $global:__croque_last_history_id = $lastCommand.Id
+    $duration = ($lastCommand.EndExecutionTime - $lastCommand.StartExecutionTime).TotalSeconds
+  }
+
+  $jobs = @(Get-Job -State Running).Count
+  $width = $Host.UI.RawUI.WindowSize.Width
+
+  # Pass the prepared info, even when empty, so that croque prompt doesn't load gh or glab again without the cache.
+  $data = foreach ($source in "git", "gh", "glab", "jj", "hg") {
+    $info = ""
+    if ($source -eq "git" -or (Get-Command $source -CommandType Application -ErrorAction SilentlyContinue)) {
+      $info = (& croque prepare $source) -join ""
+    }
+    "--data.$source=$info"
+  }
+
+  $prompt = (& croque prompt "--exit-status=$exitStatus" "--jobs=$jobs" "--duration=$duration" "--width=$width" @data powershell) -join "`n"
+
+  # Restore the exit code overwritten by croque itself.
+  $global:LASTEXITCODE = $lastExitCode
+  $prompt
+}
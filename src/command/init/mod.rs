@@ -1,14 +1,27 @@
-use crate::{command::InitArgs, shell::Shell};
+use crate::{command::InitArgs, config::Config, shell::Shell};
 
 const INIT_BASH: &str = include_str!("init.bash");
+const INIT_ELVISH: &str = include_str!("init.elv");
 const INIT_FISH: &str = include_str!("init.fish");
+const INIT_NU: &str = include_str!("init.nu");
+const INIT_POWERSHELL: &str = include_str!("init.ps1");
+const INIT_XONSH: &str = include_str!("init.xsh");
 const ASYNC_ZSH: &str = include_str!("../../../zsh-async/async.zsh");
 const INIT_ZSH: &str = include_str!("init.zsh");
+const SEMANTIC_PROMPT_BASH: &str = include_str!("semantic_prompt.bash");
+const SEMANTIC_PROMPT_FISH: &str = include_str!("semantic_prompt.fish");
+const SEMANTIC_PROMPT_ZSH: &str = include_str!("semantic_prompt.zsh");
+const TRANSIENT_FISH: &str = include_str!("transient.fish");
+const TRANSIENT_ZSH: &str = include_str!("transient.zsh");
 
 pub fn run(args: &InitArgs) {
     match &args.shell {
         Shell::Bash => print!("{INIT_BASH}"),
+        Shell::Elvish => print!("{INIT_ELVISH}"),
         Shell::Fish => print!("{INIT_FISH}"),
+        Shell::Nu => print!("{INIT_NU}"),
+        Shell::Pwsh => print!("{INIT_POWERSHELL}"),
+        Shell::Xonsh => print!("{INIT_XONSH}"),
         Shell::Zsh => {
             if !args.without_async {
                 println!("{ASYNC_ZSH}");
@@ -16,4 +29,21 @@ pub fn run(args: &InitArgs) {
             print!("{INIT_ZSH}");
         }
     }
+
+    let config = Config::load_or_default(Config::config_path());
+    if config.semantic_prompt.enabled {
+        match &args.shell {
+            Shell::Bash => print!("{SEMANTIC_PROMPT_BASH}"),
+            Shell::Fish => print!("{SEMANTIC_PROMPT_FISH}"),
+            Shell::Zsh => print!("{SEMANTIC_PROMPT_ZSH}"),
+            Shell::Elvish | Shell::Nu | Shell::Pwsh | Shell::Xonsh => {}
+        }
+    }
+    if config.transient.enabled {
+        match &args.shell {
+            Shell::Fish => print!("{TRANSIENT_FISH}"),
+            Shell::Zsh => print!("{TRANSIENT_ZSH}"),
+            Shell::Bash | Shell::Elvish | Shell::Nu | Shell::Pwsh | Shell::Xonsh => {}
+        }
+    }
 }
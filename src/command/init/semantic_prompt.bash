@@ -0,0 +1,8 @@
+
+croque::semantic-prompt::precmd() {
+  (( __croque_command_running )) && printf '\e]133;D;%s\a' "$__croque_exit_status"
+  __croque_command_running=0
+}
+
+PS0="${PS0}"'\e]133;C\a${__croque_prompt_active:0:$((__croque_command_running=1,0))}'
+PROMPT_COMMAND="${PROMPT_COMMAND/#croque::precmd/croque::precmd;croque::semantic-prompt::precmd}"
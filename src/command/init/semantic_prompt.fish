@@ -0,0 +1,8 @@
+
+function __croque_semantic_prompt_preexec --on-event fish_preexec
+  printf '\e]133;C\a'
+end
+
+function __croque_semantic_prompt_postexec --on-event fish_postexec
+  printf '\e]133;D;%s\a' $status
+end
@@ -0,0 +1,17 @@
+
+function __croque_transient_execute
+  # Replace the accepted prompt with the compact transient one.
+  if commandline --is-valid
+    set -g __croque_transient
+    commandline -f repaint
+  end
+  commandline -f execute
+end
+
+# Leave Enter alone if it has been bound by the user.
+for __croque_mode in default insert
+  if not bind --user -M $__croque_mode \r >/dev/null 2>&1
+    bind -M $__croque_mode \r __croque_transient_execute
+  end
+end
+set -e __croque_mode
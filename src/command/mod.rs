@@ -45,6 +45,12 @@ pub struct SegmentArgs {
     #[arg(short, long, help = "Prints the right prompt", default_value_t = false)]
     pub right: bool,
 
+    #[arg(long, help = "Prints the transient prompt", default_value_t = false)]
+    pub transient: bool,
+
+    #[arg(long, help = "Prints the continuation prompt", default_value_t = false)]
+    pub continuation: bool,
+
     #[arg(short = 's', long, help = "The status code of previously run command")]
     pub exit_status: i32,
 
@@ -66,21 +72,32 @@ pub struct SegmentArgs {
     #[arg(long = "data.glab")]
     pub encoded_glab_info: Option<String>,
 
+    #[arg(long = "data.jj")]
+    pub encoded_jj_info: Option<String>,
+
+    #[arg(long = "data.hg")]
+    pub encoded_hg_info: Option<String>,
+
     #[arg()]
     pub shell: Shell,
 }
 
 #[derive(Debug, clap::Args)]
 pub struct PrepareArgs {
+    #[arg(long, hide = true, help = "Reload the cached info in the foreground")]
+    pub refresh: bool,
+
     #[arg()]
     pub source: DataSource,
 }
 
-#[derive(Debug, Clone, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum DataSource {
     Git,
     Gh,
     Glab,
+    Jj,
+    Hg,
 }
 
 #[derive(Debug, clap::Args)]
@@ -1,29 +1,122 @@
 use super::PrepareArgs;
 use crate::{
     command::DataSource,
-    info::{self, gh::load_gh_info, git::load_git_info, glab::load_glab_info},
+    config::Config,
+    info::{
+        self,
+        cache::{Cache, Cached},
+        gh::load_gh_info,
+        git::{GitInfoOptions, load_git_info},
+        glab::load_glab_info,
+        hg::load_hg_info,
+        jj::load_jj_info,
+        process::timeout,
+    },
+};
+use bitcode::Encode;
+use std::{
+    process::{Command, Stdio},
+    time::Duration,
 };
 
-fn prepare_git_info() -> Option<Vec<u8>> {
-    let git_info = load_git_info()?;
+fn prepare_git_info(options: &GitInfoOptions) -> Option<Vec<u8>> {
+    let git_info = load_git_info(options)?;
     Some(bitcode::encode(&git_info))
 }
 
-fn prepare_gh_info() -> Option<Vec<u8>> {
-    let gh_info = load_gh_info()?;
-    Some(bitcode::encode(&gh_info))
+fn prepare_jj_info(timeout: Duration) -> Option<Vec<u8>> {
+    let jj_info = load_jj_info(timeout)?;
+    Some(bitcode::encode(&jj_info))
+}
+
+fn prepare_hg_info(timeout: Duration) -> Option<Vec<u8>> {
+    let hg_info = load_hg_info(timeout)?;
+    Some(bitcode::encode(&hg_info))
+}
+
+fn spawn_refresh(source: &str) -> bool {
+    let Ok(exe) = std::env::current_exe() else {
+        return false;
+    };
+
+    let mut cmd = Command::new(exe);
+    cmd.args(["prepare", "--refresh", source])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    // Detach from the shell's process group so that the refresh survives the async worker.
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+
+    cmd.spawn().is_ok()
 }
 
-fn prepare_glab_info() -> Option<Vec<u8>> {
-    let glab_info = load_glab_info()?;
-    Some(bitcode::encode(&glab_info))
+fn prepare_cached<T: Encode>(
+    source: &str,
+    ttl: u64,
+    refresh: bool,
+    prepare: impl FnOnce() -> Option<T>,
+    timed_out: impl FnOnce(&T) -> bool,
+) -> Option<Vec<u8>> {
+    let cache = match Cache::for_current_repo(source) {
+        Some(cache) if ttl > 0 => cache,
+        _ => return prepare().map(|info| bitcode::encode(&info)),
+    };
+
+    // Results of timed out commands are incomplete, so they are shown once but not cached.
+    let prepare_and_write = || {
+        let info = prepare();
+        let bytes = info.as_ref().map(bitcode::encode);
+        if !info.as_ref().is_some_and(timed_out) {
+            cache.write(bytes.as_deref());
+        }
+        bytes
+    };
+
+    if refresh {
+        let bytes = prepare_and_write();
+        cache.prune();
+        cache.unlock_refresh();
+        return bytes;
+    }
+
+    match cache.read(Duration::from_secs(ttl)) {
+        Some(Cached::Fresh(bytes)) => bytes,
+        Some(Cached::Stale(bytes)) => {
+            if cache.try_lock_refresh() && !spawn_refresh(source) {
+                cache.unlock_refresh();
+            }
+            bytes
+        }
+        None => prepare_and_write(),
+    }
 }
 
 pub fn run(args: &PrepareArgs) {
+    let config = Config::load_or_default(Config::config_path());
+    if !config.requires(args.source) {
+        return;
+    }
+
     let bytes = match args.source {
-        DataSource::Git => prepare_git_info(),
-        DataSource::Gh => prepare_gh_info(),
-        DataSource::Glab => prepare_glab_info(),
+        DataSource::Git => prepare_git_info(&config.git_info_options()),
+        DataSource::Gh => prepare_cached(
+            "gh",
+            config.gh.cache_ttl,
+            args.refresh,
+            || load_gh_info(timeout(config.gh.timeout)),
+            |gh_info| gh_info.timed_out,
+        ),
+        DataSource::Glab => prepare_cached(
+            "glab",
+            config.glab.cache_ttl,
+            args.refresh,
+            || load_glab_info(timeout(config.glab.timeout)),
+            |glab_info| glab_info.timed_out,
+        ),
+        DataSource::Jj => prepare_jj_info(timeout(config.jj.timeout)),
+        DataSource::Hg => prepare_hg_info(timeout(config.hg.timeout)),
     };
 
     if let Some(bytes) = &bytes {
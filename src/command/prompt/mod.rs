@@ -1,11 +1,14 @@
 use crate::{
-    command::SegmentArgs,
+    command::{DataSource, SegmentArgs},
     config::Config,
     info::{
         self,
         gh::{GhInfo, load_gh_info},
         git::{GitInfo, load_git_info},
         glab::{GlabInfo, load_glab_info},
+        hg::{HgInfo, load_hg_info},
+        jj::{JjInfo, load_jj_info},
+        process::timeout,
     },
     segment::{self, Context},
 };
@@ -43,19 +46,54 @@ fn decode_glab_info(encoded_glab_info: &str) -> Option<GlabInfo> {
     Some(glab_info)
 }
 
+fn decode_jj_info(encoded_jj_info: &str) -> Option<JjInfo> {
+    if encoded_jj_info.is_empty() {
+        return None;
+    }
+
+    let bytes = info::decode_base64(encoded_jj_info).ok()?;
+    let jj_info: JjInfo = bitcode::decode(&bytes).ok()?;
+
+    Some(jj_info)
+}
+
+fn decode_hg_info(encoded_hg_info: &str) -> Option<HgInfo> {
+    if encoded_hg_info.is_empty() {
+        return None;
+    }
+
+    let bytes = info::decode_base64(encoded_hg_info).ok()?;
+    let hg_info: HgInfo = bitcode::decode(&bytes).ok()?;
+
+    Some(hg_info)
+}
+
 pub fn run(args: &SegmentArgs) {
     let config = Config::load_or_default(Config::config_path());
     let git_info = match &args.encoded_git_info {
         Some(s) => decode_git_info(s),
-        None => load_git_info(),
+        None if config.requires(DataSource::Git) => load_git_info(&config.git_info_options()),
+        None => None,
     };
     let gh_info = match &args.encoded_gh_info {
         Some(s) => decode_gh_info(s),
-        None => load_gh_info(),
+        None if config.requires(DataSource::Gh) => load_gh_info(timeout(config.gh.timeout)),
+        None => None,
     };
     let glab_info = match &args.encoded_glab_info {
         Some(s) => decode_glab_info(s),
-        None => load_glab_info(),
+        None if config.requires(DataSource::Glab) => load_glab_info(timeout(config.glab.timeout)),
+        None => None,
+    };
+    let jj_info = match &args.encoded_jj_info {
+        Some(s) => decode_jj_info(s),
+        None if config.requires(DataSource::Jj) => load_jj_info(timeout(config.jj.timeout)),
+        None => None,
+    };
+    let hg_info = match &args.encoded_hg_info {
+        Some(s) => decode_hg_info(s),
+        None if config.requires(DataSource::Hg) => load_hg_info(timeout(config.hg.timeout)),
+        None => None,
     };
 
     let ctx = Context::new(
@@ -64,6 +102,8 @@ pub fn run(args: &SegmentArgs) {
         git_info.as_ref(),
         gh_info.as_ref(),
         glab_info.as_ref(),
+        jj_info.as_ref(),
+        hg_info.as_ref(),
     );
 
     segment::print_segments(&ctx).unwrap();
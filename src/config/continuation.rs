@@ -0,0 +1,49 @@
+use serde::Deserialize;
+
+use super::{
+    Line, SegmentKind,
+    style::{Color, NamedColor, Style},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ContinuationConfig {
+    #[serde(default = "ContinuationConfig::default_style")]
+    pub style: Style,
+
+    #[serde(default = "ContinuationConfig::default_content")]
+    pub content: String,
+
+    #[serde(default = "ContinuationConfig::default_segments")]
+    pub segments: Vec<Line>,
+}
+
+impl ContinuationConfig {
+    fn default_style() -> Style {
+        Style {
+            foreground: Color::Fixed(8),
+            background: Color::Named(NamedColor::White),
+            decoration: vec![],
+        }
+    }
+
+    fn default_content() -> String {
+        " ... ".to_string()
+    }
+
+    fn default_segments() -> Vec<Line> {
+        vec![Line {
+            left: vec![SegmentKind::Continuation],
+            right: vec![],
+        }]
+    }
+}
+
+impl Default for ContinuationConfig {
+    fn default() -> Self {
+        Self {
+            style: Self::default_style(),
+            content: Self::default_content(),
+            segments: Self::default_segments(),
+        }
+    }
+}
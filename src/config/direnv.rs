@@ -15,11 +15,18 @@ pub struct DirenvConfig {
     #[serde(default)]
     pub disallowed: DisallowedDirenvConfig,
 
+    #[serde(default = "DirenvConfig::default_timeout")]
+    pub timeout: f64,
+
     #[serde(default = "DirenvConfig::default_content")]
     pub content: String,
 }
 
 impl DirenvConfig {
+    fn default_timeout() -> f64 {
+        1.0
+    }
+
     fn default_content() -> String {
         " .{{.status}} ".to_string()
     }
@@ -32,6 +39,7 @@ impl Default for DirenvConfig {
             loaded: Default::default(),
             found: Default::default(),
             disallowed: Default::default(),
+            timeout: Self::default_timeout(),
             content: Self::default_content(),
         }
     }
@@ -47,6 +55,9 @@ pub struct DirenvIcons {
 
     #[serde(default = "DirenvIcons::default_disallowed")]
     pub disallowed: String,
+
+    #[serde(default = "DirenvIcons::default_unknown")]
+    pub unknown: String,
 }
 
 impl DirenvIcons {
@@ -59,6 +70,9 @@ impl DirenvIcons {
     fn default_disallowed() -> String {
         "".to_string()
     }
+    fn default_unknown() -> String {
+        "?".to_string()
+    }
 }
 
 impl Default for DirenvIcons {
@@ -67,6 +81,7 @@ impl Default for DirenvIcons {
             loaded: Self::default_loaded(),
             found: Self::default_found(),
             disallowed: Self::default_disallowed(),
+            unknown: Self::default_unknown(),
         }
     }
 }
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct GhConfig {
+    #[serde(default = "GhConfig::default_cache_ttl")]
+    pub cache_ttl: u64,
+
+    #[serde(default = "GhConfig::default_timeout")]
+    pub timeout: f64,
+}
+
+impl GhConfig {
+    fn default_cache_ttl() -> u64 {
+        60
+    }
+
+    fn default_timeout() -> f64 {
+        5.0
+    }
+}
+
+impl Default for GhConfig {
+    fn default() -> Self {
+        Self {
+            cache_ttl: Self::default_cache_ttl(),
+            timeout: Self::default_timeout(),
+        }
+    }
+}
@@ -62,6 +62,9 @@ pub struct ActionsIcons {
 
     #[serde(default = "ActionsIcons::default_skipped")]
     pub skipped: String,
+
+    #[serde(default = "ActionsIcons::default_unknown")]
+    pub unknown: String,
 }
 
 impl ActionsIcons {
@@ -84,6 +87,10 @@ impl ActionsIcons {
     fn default_skipped() -> String {
         "".to_string()
     }
+
+    fn default_unknown() -> String {
+        "?".to_string()
+    }
 }
 
 impl Default for ActionsIcons {
@@ -94,6 +101,7 @@ impl Default for ActionsIcons {
             failure: Self::default_failure(),
             cancelled: Self::default_cancelled(),
             skipped: Self::default_skipped(),
+            unknown: Self::default_unknown(),
         }
     }
 }
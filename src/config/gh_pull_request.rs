@@ -61,6 +61,12 @@ pub struct PullRequestIcons {
 
     #[serde(default = "PullRequestIcons::default_comment")]
     pub comment: String,
+
+    #[serde(default = "PullRequestIcons::default_unknown")]
+    pub unknown: String,
+
+    #[serde(default = "PullRequestIcons::default_unknown_number")]
+    pub unknown_number: String,
 }
 
 impl PullRequestIcons {
@@ -87,6 +93,14 @@ impl PullRequestIcons {
     fn default_comment() -> String {
         " ".to_string()
     }
+
+    fn default_unknown() -> String {
+        "?".to_string()
+    }
+
+    fn default_unknown_number() -> String {
+        "?".to_string()
+    }
 }
 
 impl Default for PullRequestIcons {
@@ -98,6 +112,8 @@ impl Default for PullRequestIcons {
             merged: Self::default_merged(),
             approved: Self::default_approved(),
             comment: Self::default_comment(),
+            unknown: Self::default_unknown(),
+            unknown_number: Self::default_unknown_number(),
         }
     }
 }
@@ -0,0 +1,88 @@
+use serde::Deserialize;
+
+use super::style::{Color, NamedColor, Style};
+
+#[derive(Debug, Deserialize)]
+pub struct GitCommitConfig {
+    /// Commits older than this many seconds are considered stale.
+    #[serde(default = "GitCommitConfig::default_stale_after")]
+    pub stale_after: u64,
+
+    #[serde(default)]
+    pub fresh: FreshCommitConfig,
+
+    #[serde(default)]
+    pub stale: StaleCommitConfig,
+
+    #[serde(default = "GitCommitConfig::default_content")]
+    pub content: String,
+}
+
+impl GitCommitConfig {
+    fn default_stale_after() -> u64 {
+        7 * 24 * 60 * 60
+    }
+
+    fn default_content() -> String {
+        " {{.commit_age}} by {{.commit_author}} ".to_string()
+    }
+}
+
+impl Default for GitCommitConfig {
+    fn default() -> Self {
+        Self {
+            stale_after: Self::default_stale_after(),
+            fresh: Default::default(),
+            stale: Default::default(),
+            content: Self::default_content(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FreshCommitConfig {
+    #[serde(default = "FreshCommitConfig::default_style")]
+    pub style: Style,
+}
+
+impl FreshCommitConfig {
+    fn default_style() -> Style {
+        Style {
+            foreground: Color::Named(NamedColor::Black),
+            background: Color::Fixed(250),
+            decoration: vec![],
+        }
+    }
+}
+
+impl Default for FreshCommitConfig {
+    fn default() -> Self {
+        Self {
+            style: Self::default_style(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StaleCommitConfig {
+    #[serde(default = "StaleCommitConfig::default_style")]
+    pub style: Style,
+}
+
+impl StaleCommitConfig {
+    fn default_style() -> Style {
+        Style {
+            foreground: Color::Named(NamedColor::Black),
+            background: Color::Fixed(214),
+            decoration: vec![],
+        }
+    }
+}
+
+impl Default for StaleCommitConfig {
+    fn default() -> Self {
+        Self {
+            style: Self::default_style(),
+        }
+    }
+}
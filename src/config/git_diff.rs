@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+use super::style::{Color, NamedColor, Style};
+
+#[derive(Debug, Deserialize)]
+pub struct GitDiffConfig {
+    #[serde(default = "GitDiffConfig::default_max_files")]
+    pub max_files: usize,
+
+    #[serde(default = "GitDiffConfig::default_style")]
+    pub style: Style,
+
+    #[serde(default = "GitDiffConfig::default_content")]
+    pub content: String,
+}
+
+impl GitDiffConfig {
+    fn default_max_files() -> usize {
+        1000
+    }
+
+    fn default_style() -> Style {
+        Style {
+            foreground: Color::Named(NamedColor::Black),
+            background: Color::Named(NamedColor::Cyan),
+            decoration: vec![],
+        }
+    }
+
+    fn default_content() -> String {
+        " +{{.insertions}} −{{.deletions}} ".to_string()
+    }
+}
+
+impl Default for GitDiffConfig {
+    fn default() -> Self {
+        Self {
+            max_files: Self::default_max_files(),
+            style: Self::default_style(),
+            content: Self::default_content(),
+        }
+    }
+}
@@ -6,6 +6,9 @@ pub struct GitStatusConfig {
     #[serde(default)]
     pub icons: GitStatusIcons,
 
+    #[serde(default)]
+    pub working_tree: WorkingTreeConfig,
+
     #[serde(default)]
     pub clean: CleanStatusConfig,
 
@@ -18,9 +21,15 @@ pub struct GitStatusConfig {
     #[serde(default)]
     pub conflicted: ConflictedStatusConfig,
 
+    #[serde(default)]
+    pub operation: OperationStatusConfig,
+
     #[serde(default = "GitStatusConfig::default_remotes")]
     pub remotes: Vec<RemoteConfig>,
 
+    #[serde(default)]
+    pub base_branch: Vec<String>,
+
     #[serde(default = "GitStatusConfig::default_display_master")]
     pub display_master: bool,
 
@@ -30,6 +39,9 @@ pub struct GitStatusConfig {
     #[serde(default = "GitStatusConfig::default_commit_hash_length")]
     pub commit_hash_length: usize,
 
+    #[serde(default = "GitStatusConfig::default_lfs_max_files")]
+    pub lfs_max_files: usize,
+
     #[serde(default = "GitStatusConfig::default_content")]
     pub content: String,
 }
@@ -56,6 +68,10 @@ impl GitStatusConfig {
         7
     }
 
+    fn default_lfs_max_files() -> usize {
+        1000
+    }
+
     fn default_content() -> String {
         " {{.remote}}{{.head}}{{.working_tree}}{{.upstream}} ".to_string()
     }
@@ -65,14 +81,18 @@ impl Default for GitStatusConfig {
     fn default() -> Self {
         Self {
             icons: Default::default(),
+            working_tree: Default::default(),
             clean: Default::default(),
             unstaged: Default::default(),
             staged: Default::default(),
             conflicted: Default::default(),
+            operation: Default::default(),
             remotes: Self::default_remotes(),
+            base_branch: Default::default(),
             display_master: Self::default_display_master(),
             branch_aliases: Default::default(),
             commit_hash_length: Self::default_commit_hash_length(),
+            lfs_max_files: Self::default_lfs_max_files(),
             content: Self::default_content(),
         }
     }
@@ -89,6 +109,12 @@ pub struct GitStatusIcons {
     #[serde(default = "GitStatusIcons::default_commit")]
     pub commit: String,
 
+    #[serde(default = "GitStatusIcons::default_worktree")]
+    pub worktree: String,
+
+    #[serde(default = "GitStatusIcons::default_submodule")]
+    pub submodule: String,
+
     #[serde(default = "GitStatusIcons::default_modified")]
     pub modified: String,
 
@@ -109,6 +135,48 @@ pub struct GitStatusIcons {
 
     #[serde(default = "GitStatusIcons::default_ahead")]
     pub ahead: String,
+
+    #[serde(default = "GitStatusIcons::default_no_upstream")]
+    pub no_upstream: String,
+
+    #[serde(default = "GitStatusIcons::default_gone_upstream")]
+    pub gone_upstream: String,
+
+    #[serde(default = "GitStatusIcons::default_base_behind")]
+    pub base_behind: String,
+
+    #[serde(default = "GitStatusIcons::default_base_ahead")]
+    pub base_ahead: String,
+
+    #[serde(default = "GitStatusIcons::default_stash")]
+    pub stash: String,
+
+    #[serde(default = "GitStatusIcons::default_lfs")]
+    pub lfs: String,
+
+    #[serde(default = "GitStatusIcons::default_lfs_unsmudged")]
+    pub lfs_unsmudged: String,
+
+    #[serde(default = "GitStatusIcons::default_sparse")]
+    pub sparse: String,
+
+    #[serde(default = "GitStatusIcons::default_merge")]
+    pub merge: String,
+
+    #[serde(default = "GitStatusIcons::default_revert")]
+    pub revert: String,
+
+    #[serde(default = "GitStatusIcons::default_cherry_pick")]
+    pub cherry_pick: String,
+
+    #[serde(default = "GitStatusIcons::default_bisect")]
+    pub bisect: String,
+
+    #[serde(default = "GitStatusIcons::default_rebase")]
+    pub rebase: String,
+
+    #[serde(default = "GitStatusIcons::default_apply_mailbox")]
+    pub apply_mailbox: String,
 }
 
 impl GitStatusIcons {
@@ -121,6 +189,12 @@ impl GitStatusIcons {
     fn default_commit() -> String {
         "".to_string()
     }
+    fn default_worktree() -> String {
+        "⊞".to_string()
+    }
+    fn default_submodule() -> String {
+        "⊂".to_string()
+    }
     fn default_modified() -> String {
         "…".to_string()
     }
@@ -142,6 +216,48 @@ impl GitStatusIcons {
     fn default_ahead() -> String {
         "".to_string()
     }
+    fn default_no_upstream() -> String {
+        "".to_string()
+    }
+    fn default_gone_upstream() -> String {
+        "".to_string()
+    }
+    fn default_base_behind() -> String {
+        "↓".to_string()
+    }
+    fn default_base_ahead() -> String {
+        "↑".to_string()
+    }
+    fn default_stash() -> String {
+        "≡".to_string()
+    }
+    fn default_lfs() -> String {
+        "⛁".to_string()
+    }
+    fn default_lfs_unsmudged() -> String {
+        "⛁!".to_string()
+    }
+    fn default_sparse() -> String {
+        "◐".to_string()
+    }
+    fn default_merge() -> String {
+        "MERGING".to_string()
+    }
+    fn default_revert() -> String {
+        "REVERTING".to_string()
+    }
+    fn default_cherry_pick() -> String {
+        "CHERRY-PICKING".to_string()
+    }
+    fn default_bisect() -> String {
+        "BISECTING".to_string()
+    }
+    fn default_rebase() -> String {
+        "REBASING".to_string()
+    }
+    fn default_apply_mailbox() -> String {
+        "AM".to_string()
+    }
 }
 
 impl Default for GitStatusIcons {
@@ -150,6 +266,8 @@ impl Default for GitStatusIcons {
             branch: Self::default_branch(),
             tag: Self::default_tag(),
             commit: Self::default_commit(),
+            worktree: Self::default_worktree(),
+            submodule: Self::default_submodule(),
             modified: Self::default_modified(),
             added: Self::default_added(),
             deleted: Self::default_deleted(),
@@ -157,6 +275,110 @@ impl Default for GitStatusIcons {
             conflicted: Self::default_conflicted(),
             behind: Self::default_behind(),
             ahead: Self::default_ahead(),
+            no_upstream: Self::default_no_upstream(),
+            gone_upstream: Self::default_gone_upstream(),
+            base_behind: Self::default_base_behind(),
+            base_ahead: Self::default_base_ahead(),
+            stash: Self::default_stash(),
+            lfs: Self::default_lfs(),
+            lfs_unsmudged: Self::default_lfs_unsmudged(),
+            sparse: Self::default_sparse(),
+            merge: Self::default_merge(),
+            revert: Self::default_revert(),
+            cherry_pick: Self::default_cherry_pick(),
+            bisect: Self::default_bisect(),
+            rebase: Self::default_rebase(),
+            apply_mailbox: Self::default_apply_mailbox(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WorkingTreeConfig {
+    #[serde(default = "WorkingTreeConfig::default_counts")]
+    pub counts: bool,
+
+    #[serde(default = "WorkingTreeConfig::default_staged_new")]
+    pub staged_new: String,
+
+    #[serde(default = "WorkingTreeConfig::default_staged_modified")]
+    pub staged_modified: String,
+
+    #[serde(default = "WorkingTreeConfig::default_staged_deleted")]
+    pub staged_deleted: String,
+
+    #[serde(default = "WorkingTreeConfig::default_staged_renamed")]
+    pub staged_renamed: String,
+
+    #[serde(default = "WorkingTreeConfig::default_unstaged_new")]
+    pub unstaged_new: String,
+
+    #[serde(default = "WorkingTreeConfig::default_unstaged_modified")]
+    pub unstaged_modified: String,
+
+    #[serde(default = "WorkingTreeConfig::default_unstaged_deleted")]
+    pub unstaged_deleted: String,
+
+    #[serde(default = "WorkingTreeConfig::default_unstaged_renamed")]
+    pub unstaged_renamed: String,
+
+    #[serde(default = "WorkingTreeConfig::default_conflicted")]
+    pub conflicted: String,
+
+    #[serde(default = "WorkingTreeConfig::default_separator")]
+    pub separator: String,
+}
+
+impl WorkingTreeConfig {
+    fn default_counts() -> bool {
+        false
+    }
+    fn default_staged_new() -> String {
+        "+{{.count}}".to_string()
+    }
+    fn default_staged_modified() -> String {
+        "~{{.count}}".to_string()
+    }
+    fn default_staged_deleted() -> String {
+        "-{{.count}}".to_string()
+    }
+    fn default_staged_renamed() -> String {
+        "→{{.count}}".to_string()
+    }
+    fn default_unstaged_new() -> String {
+        "?{{.count}}".to_string()
+    }
+    fn default_unstaged_modified() -> String {
+        "✚{{.count}}".to_string()
+    }
+    fn default_unstaged_deleted() -> String {
+        "✖{{.count}}".to_string()
+    }
+    fn default_unstaged_renamed() -> String {
+        "»{{.count}}".to_string()
+    }
+    fn default_conflicted() -> String {
+        "!{{.count}}".to_string()
+    }
+    fn default_separator() -> String {
+        " | ".to_string()
+    }
+}
+
+impl Default for WorkingTreeConfig {
+    fn default() -> Self {
+        Self {
+            counts: Self::default_counts(),
+            staged_new: Self::default_staged_new(),
+            staged_modified: Self::default_staged_modified(),
+            staged_deleted: Self::default_staged_deleted(),
+            staged_renamed: Self::default_staged_renamed(),
+            unstaged_new: Self::default_unstaged_new(),
+            unstaged_modified: Self::default_unstaged_modified(),
+            unstaged_deleted: Self::default_unstaged_deleted(),
+            unstaged_renamed: Self::default_unstaged_renamed(),
+            conflicted: Self::default_conflicted(),
+            separator: Self::default_separator(),
         }
     }
 }
@@ -257,6 +479,30 @@ impl Default for ConflictedStatusConfig {
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct OperationStatusConfig {
+    #[serde(default = "OperationStatusConfig::default_style")]
+    pub style: Style,
+}
+
+impl OperationStatusConfig {
+    fn default_style() -> Style {
+        Style {
+            foreground: Color::Named(NamedColor::Black),
+            background: Color::Named(NamedColor::Magenta),
+            decoration: vec![],
+        }
+    }
+}
+
+impl Default for OperationStatusConfig {
+    fn default() -> Self {
+        Self {
+            style: Self::default_style(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RemoteConfig {
     pub pattern: String,
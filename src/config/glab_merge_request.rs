@@ -76,6 +76,12 @@ pub struct MergeRequestIcons {
 
     #[serde(default = "MergeRequestIcons::default_comment")]
     pub comment: String,
+
+    #[serde(default = "MergeRequestIcons::default_unknown")]
+    pub unknown: String,
+
+    #[serde(default = "MergeRequestIcons::default_unknown_number")]
+    pub unknown_number: String,
 }
 
 impl MergeRequestIcons {
@@ -122,6 +128,14 @@ impl MergeRequestIcons {
     fn default_comment() -> String {
         " ".to_string()
     }
+
+    fn default_unknown() -> String {
+        "?".to_string()
+    }
+
+    fn default_unknown_number() -> String {
+        "?".to_string()
+    }
 }
 
 impl Default for MergeRequestIcons {
@@ -138,6 +152,8 @@ impl Default for MergeRequestIcons {
             pipeline_canceled: Self::default_pipeline_canceled(),
             approved: Self::default_approved(),
             comment: Self::default_comment(),
+            unknown: Self::default_unknown(),
+            unknown_number: Self::default_unknown_number(),
         }
     }
 }
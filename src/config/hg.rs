@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct HgConfig {
+    #[serde(default = "HgConfig::default_timeout")]
+    pub timeout: f64,
+}
+
+impl HgConfig {
+    fn default_timeout() -> f64 {
+        1.0
+    }
+}
+
+impl Default for HgConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Self::default_timeout(),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use serde::Deserialize;
+
+use super::style::{Color, NamedColor, Style};
+
+#[derive(Debug, Deserialize)]
+pub struct HgStatusConfig {
+    #[serde(default)]
+    pub icons: HgStatusIcons,
+
+    #[serde(default)]
+    pub clean: CleanHgStatusConfig,
+
+    #[serde(default)]
+    pub dirty: DirtyHgStatusConfig,
+
+    #[serde(default = "HgStatusConfig::default_content")]
+    pub content: String,
+}
+
+impl HgStatusConfig {
+    fn default_content() -> String {
+        " {{.branch}}{{.bookmark}}{{.working_tree}} ".to_string()
+    }
+}
+
+impl Default for HgStatusConfig {
+    fn default() -> Self {
+        Self {
+            icons: Default::default(),
+            clean: Default::default(),
+            dirty: Default::default(),
+            content: Self::default_content(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HgStatusIcons {
+    #[serde(default = "HgStatusIcons::default_branch")]
+    pub branch: String,
+
+    #[serde(default = "HgStatusIcons::default_bookmark")]
+    pub bookmark: String,
+
+    #[serde(default = "HgStatusIcons::default_modified")]
+    pub modified: String,
+
+    #[serde(default = "HgStatusIcons::default_untracked")]
+    pub untracked: String,
+
+    #[serde(default = "HgStatusIcons::default_unknown")]
+    pub unknown: String,
+}
+
+impl HgStatusIcons {
+    fn default_branch() -> String {
+        "☿".to_string()
+    }
+    fn default_bookmark() -> String {
+        "⚑".to_string()
+    }
+    fn default_modified() -> String {
+        "…".to_string()
+    }
+    fn default_untracked() -> String {
+        "+".to_string()
+    }
+    fn default_unknown() -> String {
+        "?".to_string()
+    }
+}
+
+impl Default for HgStatusIcons {
+    fn default() -> Self {
+        Self {
+            branch: Self::default_branch(),
+            bookmark: Self::default_bookmark(),
+            modified: Self::default_modified(),
+            untracked: Self::default_untracked(),
+            unknown: Self::default_unknown(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CleanHgStatusConfig {
+    #[serde(default = "CleanHgStatusConfig::default_style")]
+    pub style: Style,
+}
+
+impl CleanHgStatusConfig {
+    fn default_style() -> Style {
+        Style {
+            foreground: Color::Named(NamedColor::Black),
+            background: Color::Named(NamedColor::Green),
+            decoration: vec![],
+        }
+    }
+}
+
+impl Default for CleanHgStatusConfig {
+    fn default() -> Self {
+        Self {
+            style: Self::default_style(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DirtyHgStatusConfig {
+    #[serde(default = "DirtyHgStatusConfig::default_style")]
+    pub style: Style,
+}
+
+impl DirtyHgStatusConfig {
+    fn default_style() -> Style {
+        Style {
+            foreground: Color::Named(NamedColor::Black),
+            background: Color::Named(NamedColor::Yellow),
+            decoration: vec![],
+        }
+    }
+}
+
+impl Default for DirtyHgStatusConfig {
+    fn default() -> Self {
+        Self {
+            style: Self::default_style(),
+        }
+    }
+}
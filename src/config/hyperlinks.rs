@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct HyperlinksConfig {
+    #[serde(default = "HyperlinksConfig::default_enabled")]
+    pub enabled: bool,
+}
+
+impl HyperlinksConfig {
+    fn default_enabled() -> bool {
+        false
+    }
+}
+
+impl Default for HyperlinksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+        }
+    }
+}
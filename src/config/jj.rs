@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct JjConfig {
+    #[serde(default = "JjConfig::default_timeout")]
+    pub timeout: f64,
+}
+
+impl JjConfig {
+    fn default_timeout() -> f64 {
+        1.0
+    }
+}
+
+impl Default for JjConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Self::default_timeout(),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use serde::Deserialize;
+
+use super::style::{Color, NamedColor, Style};
+
+#[derive(Debug, Deserialize)]
+pub struct JjStatusConfig {
+    #[serde(default)]
+    pub icons: JjStatusIcons,
+
+    #[serde(default)]
+    pub normal: NormalJjStatusConfig,
+
+    #[serde(default)]
+    pub conflicted: ConflictedJjStatusConfig,
+
+    #[serde(default = "JjStatusConfig::default_content")]
+    pub content: String,
+}
+
+impl JjStatusConfig {
+    fn default_content() -> String {
+        " {{.change_id}}{{.bookmarks}}{{.conflict}} ".to_string()
+    }
+}
+
+impl Default for JjStatusConfig {
+    fn default() -> Self {
+        Self {
+            icons: Default::default(),
+            normal: Default::default(),
+            conflicted: Default::default(),
+            content: Self::default_content(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JjStatusIcons {
+    #[serde(default = "JjStatusIcons::default_change")]
+    pub change: String,
+
+    #[serde(default = "JjStatusIcons::default_bookmark")]
+    pub bookmark: String,
+
+    #[serde(default = "JjStatusIcons::default_conflict")]
+    pub conflict: String,
+}
+
+impl JjStatusIcons {
+    fn default_change() -> String {
+        "@".to_string()
+    }
+    fn default_bookmark() -> String {
+        "⚑".to_string()
+    }
+    fn default_conflict() -> String {
+        "✘".to_string()
+    }
+}
+
+impl Default for JjStatusIcons {
+    fn default() -> Self {
+        Self {
+            change: Self::default_change(),
+            bookmark: Self::default_bookmark(),
+            conflict: Self::default_conflict(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NormalJjStatusConfig {
+    #[serde(default = "NormalJjStatusConfig::default_style")]
+    pub style: Style,
+}
+
+impl NormalJjStatusConfig {
+    fn default_style() -> Style {
+        Style {
+            foreground: Color::Named(NamedColor::Black),
+            background: Color::Named(NamedColor::Magenta),
+            decoration: vec![],
+        }
+    }
+}
+
+impl Default for NormalJjStatusConfig {
+    fn default() -> Self {
+        Self {
+            style: Self::default_style(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConflictedJjStatusConfig {
+    #[serde(default = "ConflictedJjStatusConfig::default_style")]
+    pub style: Style,
+}
+
+impl ConflictedJjStatusConfig {
+    fn default_style() -> Style {
+        Style {
+            foreground: Color::Named(NamedColor::Black),
+            background: Color::Named(NamedColor::Red),
+            decoration: vec![],
+        }
+    }
+}
+
+impl Default for ConflictedJjStatusConfig {
+    fn default() -> Self {
+        Self {
+            style: Self::default_style(),
+        }
+    }
+}
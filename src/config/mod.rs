@@ -1,23 +1,40 @@
+pub mod continuation;
 pub mod direnv;
 pub mod duration;
+pub mod gh;
 pub mod gh_actions;
 pub mod gh_pull_request;
+pub mod git_commit;
+pub mod git_diff;
 pub mod git_status;
 pub mod git_user;
+pub mod glab;
 pub mod glab_merge_request;
+pub mod hg;
+pub mod hg_status;
+pub mod hyperlinks;
+pub mod jj;
+pub mod jj_status;
 pub mod os;
 pub mod path;
+pub mod semantic_prompt;
 pub mod status;
 pub mod style;
 pub mod time;
+pub mod title;
+pub mod transient;
 pub mod user;
 
-use crate::config::gh_actions::GhActionsConfig;
+use crate::{command::DataSource, config::gh_actions::GhActionsConfig, info::git::GitInfoOptions};
 
 use self::{
-    duration::DurationConfig, gh_pull_request::GhPullRequestConfig, git_status::GitStatusConfig,
-    git_user::GitUserConfig, glab_merge_request::GlabMergeRequestConfig, os::OsConfig,
-    path::PathConfig, status::StatusConfig, time::TimeConfig, user::UserConfig,
+    continuation::ContinuationConfig, duration::DurationConfig, gh::GhConfig,
+    gh_pull_request::GhPullRequestConfig, git_commit::GitCommitConfig, git_diff::GitDiffConfig,
+    git_status::GitStatusConfig, git_user::GitUserConfig, glab::GlabConfig,
+    glab_merge_request::GlabMergeRequestConfig, hg::HgConfig, hg_status::HgStatusConfig,
+    hyperlinks::HyperlinksConfig, jj::JjConfig, jj_status::JjStatusConfig, os::OsConfig,
+    path::PathConfig, semantic_prompt::SemanticPromptConfig, status::StatusConfig,
+    time::TimeConfig, title::TitleConfig, transient::TransientConfig, user::UserConfig,
 };
 use direnv::DirenvConfig;
 use serde::Deserialize;
@@ -53,6 +70,12 @@ pub struct Config {
     #[serde(default)]
     pub git_status: GitStatusConfig,
 
+    #[serde(default)]
+    pub git_diff: GitDiffConfig,
+
+    #[serde(default)]
+    pub git_commit: GitCommitConfig,
+
     #[serde(default)]
     pub git_user: GitUserConfig,
 
@@ -65,14 +88,47 @@ pub struct Config {
     #[serde(default)]
     pub glab_merge_request: GlabMergeRequestConfig,
 
+    #[serde(default)]
+    pub jj_status: JjStatusConfig,
+
+    #[serde(default)]
+    pub hg_status: HgStatusConfig,
+
     #[serde(default)]
     pub direnv: DirenvConfig,
 
+    #[serde(default)]
+    pub gh: GhConfig,
+
+    #[serde(default)]
+    pub glab: GlabConfig,
+
+    #[serde(default)]
+    pub jj: JjConfig,
+
+    #[serde(default)]
+    pub hg: HgConfig,
+
+    #[serde(default)]
+    pub semantic_prompt: SemanticPromptConfig,
+
+    #[serde(default)]
+    pub hyperlinks: HyperlinksConfig,
+
+    #[serde(default)]
+    pub title: TitleConfig,
+
     #[serde(default)]
     pub segment_separators: SegmentSeparators,
 
     #[serde(default = "Config::default_segments")]
     pub segments: Vec<Line>,
+
+    #[serde(default)]
+    pub transient: TransientConfig,
+
+    #[serde(default)]
+    pub continuation: ContinuationConfig,
 }
 
 impl Config {
@@ -118,6 +174,52 @@ impl Config {
         }
     }
 
+    fn segment_kinds(&self) -> impl Iterator<Item = &SegmentKind> {
+        self.segments
+            .iter()
+            .chain(self.transient.segments.iter())
+            .chain(self.continuation.segments.iter())
+            .flat_map(|line| line.left.iter().chain(line.right.iter()))
+    }
+
+    pub fn requires(&self, source: DataSource) -> bool {
+        self.segment_kinds()
+            .any(|segment| segment.data_source() == Some(source))
+            || source == DataSource::Git && self.title.requires_git()
+    }
+
+    pub fn contains(&self, kind: SegmentKind) -> bool {
+        self.segment_kinds().any(|segment| *segment == kind)
+    }
+
+    pub fn git_info_options(&self) -> GitInfoOptions<'_> {
+        GitInfoOptions {
+            diff_max_files: self
+                .contains(SegmentKind::GitDiff)
+                .then_some(self.git_diff.max_files),
+            base_branches: if self.git_status_uses("{{.base}}") {
+                &self.git_status.base_branch
+            } else {
+                &[]
+            },
+            operation: self.git_status_uses("{{.operation}}"),
+            stash: self.git_status_uses("{{.stash}}"),
+            worktree: self.git_status_uses("{{.worktree}}"),
+            // The path segment is shortened relative to the superproject as well.
+            superproject: self.git_status_uses("{{.submodule}}")
+                || self.contains(SegmentKind::Path),
+            commit: self.contains(SegmentKind::GitCommit),
+            lfs_max_files: self
+                .git_status_uses("{{.lfs}}")
+                .then_some(self.git_status.lfs_max_files),
+            sparse_checkout: self.git_status_uses("{{.sparse}}"),
+        }
+    }
+
+    fn git_status_uses(&self, placeholder: &str) -> bool {
+        self.contains(SegmentKind::GitStatus) && self.git_status.content.contains(placeholder)
+    }
+
     fn default_segments() -> Vec<Line> {
         vec![
             Line {
@@ -154,13 +256,26 @@ impl Default for Config {
             duration: Default::default(),
             time: Default::default(),
             git_status: Default::default(),
+            git_diff: Default::default(),
+            git_commit: Default::default(),
             git_user: Default::default(),
             gh_actions: Default::default(),
             gh_pull_request: Default::default(),
             glab_merge_request: Default::default(),
+            jj_status: Default::default(),
+            hg_status: Default::default(),
             direnv: Default::default(),
+            gh: Default::default(),
+            glab: Default::default(),
+            jj: Default::default(),
+            hg: Default::default(),
+            semantic_prompt: Default::default(),
+            hyperlinks: Default::default(),
+            title: Default::default(),
             segment_separators: Default::default(),
             segments: Self::default_segments(),
+            transient: Default::default(),
+            continuation: Default::default(),
         }
     }
 }
@@ -218,7 +333,7 @@ pub struct Line {
     pub right: Vec<SegmentKind>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SegmentKind {
     Duration,
@@ -228,9 +343,199 @@ pub enum SegmentKind {
     Time,
     User,
     GitStatus,
+    GitDiff,
+    GitCommit,
     GitUser,
     GhPullRequest,
     GhActions,
     GlabMergeRequest,
+    JjStatus,
+    HgStatus,
     Direnv,
+    Continuation,
+}
+
+impl SegmentKind {
+    pub fn data_source(&self) -> Option<DataSource> {
+        match self {
+            SegmentKind::Path
+            | SegmentKind::GitStatus
+            | SegmentKind::GitDiff
+            | SegmentKind::GitCommit
+            | SegmentKind::GitUser => Some(DataSource::Git),
+            SegmentKind::GhPullRequest | SegmentKind::GhActions => Some(DataSource::Gh),
+            SegmentKind::GlabMergeRequest => Some(DataSource::Glab),
+            SegmentKind::JjStatus => Some(DataSource::Jj),
+            SegmentKind::HgStatus => Some(DataSource::Hg),
+            SegmentKind::Duration
+            | SegmentKind::Os
+            | SegmentKind::Status
+            | SegmentKind::Time
+            | SegmentKind::User
+            | SegmentKind::Direnv
+            | SegmentKind::Continuation => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires() {
+        let config = Config::default();
+        assert!(config.requires(DataSource::Git));
+        assert!(config.requires(DataSource::Gh));
+        assert!(config.requires(DataSource::Glab));
+
+        let config = Config::load_from_str(
+            "
+            segments:
+              - left: [path, git_status]
+                right: [time]
+            ",
+        )
+        .unwrap();
+        assert!(config.requires(DataSource::Git));
+        assert!(!config.requires(DataSource::Gh));
+        assert!(!config.requires(DataSource::Glab));
+
+        let config = Config::load_from_str(
+            "
+            segments:
+              - left: [status]
+                right: [gh_actions]
+            ",
+        )
+        .unwrap();
+        assert!(!config.requires(DataSource::Git));
+        assert!(config.requires(DataSource::Gh));
+        assert!(!config.requires(DataSource::Glab));
+
+        let config = Config::load_from_str(
+            "
+            segments:
+              - left: [path]
+                right: [time]
+            title:
+              enabled: true
+            ",
+        )
+        .unwrap();
+        assert!(config.requires(DataSource::Git));
+        assert!(!config.requires(DataSource::Gh));
+        assert!(!config.requires(DataSource::Glab));
+    }
+
+    #[test]
+    fn test_contains() {
+        let config = Config::default();
+        assert!(config.contains(SegmentKind::GitStatus));
+        assert!(!config.contains(SegmentKind::GitDiff));
+
+        let config = Config::load_from_str(
+            "
+            segments:
+              - left: [path]
+            transient:
+              segments:
+                - left: [git_diff]
+            ",
+        )
+        .unwrap();
+        assert!(!config.contains(SegmentKind::GitStatus));
+        assert!(config.contains(SegmentKind::GitDiff));
+    }
+
+    #[test]
+    fn test_git_info_options() {
+        let config = Config::default();
+        let options = config.git_info_options();
+        assert!(!options.operation);
+        assert!(!options.stash);
+        assert!(options.base_branches.is_empty());
+        assert!(!options.worktree);
+        assert!(options.superproject);
+        assert!(!options.commit);
+        assert_eq!(options.lfs_max_files, None);
+        assert!(!options.sparse_checkout);
+
+        let config = Config::load_from_str(
+            "
+            segments:
+              - left: [path, git_user]
+                right: [git_commit]
+            ",
+        )
+        .unwrap();
+        let options = config.git_info_options();
+        assert!(!options.operation);
+        assert!(!options.stash);
+        assert!(!options.worktree);
+        assert!(options.superproject);
+        assert!(options.commit);
+
+        let config = Config::load_from_str(
+            "
+            segments:
+              - left: [git_status]
+            git_status:
+              content: '{{.head}}'
+            ",
+        )
+        .unwrap();
+        let options = config.git_info_options();
+        assert!(!options.worktree);
+        assert!(!options.superproject);
+        assert_eq!(options.lfs_max_files, None);
+        assert!(!options.sparse_checkout);
+
+        let config = Config::load_from_str(
+            "
+            git_status:
+              content: '{{.head}}'
+            ",
+        )
+        .unwrap();
+        let options = config.git_info_options();
+        assert!(!options.operation);
+        assert!(!options.stash);
+
+        let config = Config::load_from_str(
+            "
+            git_status:
+              content: '{{.head}}{{.operation}}{{.stash}}{{.worktree}}{{.lfs}}{{.sparse}}'
+            ",
+        )
+        .unwrap();
+        let options = config.git_info_options();
+        assert!(options.operation);
+        assert!(options.stash);
+        assert!(options.worktree);
+        assert_eq!(options.lfs_max_files, Some(1000));
+        assert!(options.sparse_checkout);
+
+        let config = Config::load_from_str(
+            "
+            git_status:
+              base_branch: [main]
+              content: '{{.head}}{{.base}}'
+            ",
+        )
+        .unwrap();
+        let options = config.git_info_options();
+        assert_eq!(options.base_branches, ["main"]);
+
+        let config = Config::load_from_str(
+            "
+            git_status:
+              base_branch: [main]
+              content: '{{.head}}'
+            ",
+        )
+        .unwrap();
+        let options = config.git_info_options();
+        assert!(options.base_branches.is_empty());
+    }
 }
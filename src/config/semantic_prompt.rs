@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SemanticPromptConfig {
+    #[serde(default = "SemanticPromptConfig::default_enabled")]
+    pub enabled: bool,
+}
+
+impl SemanticPromptConfig {
+    fn default_enabled() -> bool {
+        false
+    }
+}
+
+impl Default for SemanticPromptConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct TitleConfig {
+    #[serde(default = "TitleConfig::default_enabled")]
+    pub enabled: bool,
+
+    #[serde(default = "TitleConfig::default_content")]
+    pub content: String,
+
+    #[serde(default = "TitleConfig::default_report_cwd")]
+    pub report_cwd: bool,
+}
+
+impl TitleConfig {
+    pub fn requires_git(&self) -> bool {
+        self.enabled && self.content.contains("{{.git_head}}")
+    }
+
+    fn default_enabled() -> bool {
+        false
+    }
+
+    fn default_content() -> String {
+        "{{.user}}@{{.host}}: {{.path}} {{.git_head}}".to_string()
+    }
+
+    fn default_report_cwd() -> bool {
+        false
+    }
+}
+
+impl Default for TitleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            content: Self::default_content(),
+            report_cwd: Self::default_report_cwd(),
+        }
+    }
+}
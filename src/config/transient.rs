@@ -0,0 +1,32 @@
+use super::{Line, SegmentKind};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct TransientConfig {
+    #[serde(default = "TransientConfig::default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "TransientConfig::default_segments")]
+    pub segments: Vec<Line>,
+}
+
+impl TransientConfig {
+    fn default_enabled() -> bool {
+        false
+    }
+
+    fn default_segments() -> Vec<Line> {
+        vec![Line {
+            left: vec![SegmentKind::Status],
+            right: vec![],
+        }]
+    }
+}
+
+impl Default for TransientConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            segments: Self::default_segments(),
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use bitcode::{Decode, Encode};
+use git2::Repository;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const XDG_CACHE_HOME: &str = "XDG_CACHE_HOME";
+
+// A refresh that has not finished within this period is considered dead.
+const REFRESH_LOCK_TIMEOUT: Duration = Duration::from_secs(60);
+
+// Entries that have not been refreshed for this period belong to removed repositories or branches.
+const ENTRY_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+#[derive(Debug, Encode, Decode)]
+struct CacheEntry {
+    created_at: u64,
+    head: Option<String>,
+    bytes: Option<Vec<u8>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Cached {
+    Fresh(Option<Vec<u8>>),
+    Stale(Option<Vec<u8>>),
+}
+
+#[derive(Debug)]
+pub struct Cache {
+    path: PathBuf,
+    head: Option<String>,
+}
+
+fn cache_dir() -> Option<PathBuf> {
+    if let Some(xdg_cache_home) = std::env::var_os(XDG_CACHE_HOME) {
+        let mut path = PathBuf::from(xdg_cache_home);
+        path.push("croque");
+        Some(path)
+    } else {
+        let mut path = dirs::home_dir()?;
+        path.push(".cache/croque");
+        Some(path)
+    }
+}
+
+/// FNV-1a over the given parts, which unlike `DefaultHasher` is stable across Rust releases.
+fn fnv1a(parts: &[&[u8]]) -> u64 {
+    parts
+        .iter()
+        .flat_map(|part| part.iter().chain(&[0]))
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+        })
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+impl Cache {
+    /// Returns the cache slot of `source` for the repository and branch of the current directory.
+    ///
+    /// The HEAD commit is stored in the entry rather than in the key, so that new commits replace
+    /// the entry instead of adding another file.
+    pub fn for_current_repo(source: &str) -> Option<Self> {
+        let current_dir = std::env::current_dir().ok()?;
+        let repo = Repository::discover(current_dir).ok()?;
+        let workdir = repo.workdir()?;
+        let head_ref = repo.head().ok();
+        let branch = head_ref.as_ref().and_then(|r| r.shorthand().ok());
+        let head = head_ref
+            .as_ref()
+            .and_then(|r| r.target())
+            .map(|oid| oid.to_string());
+
+        let key = fnv1a(&[
+            workdir.as_os_str().as_encoded_bytes(),
+            branch.unwrap_or_default().as_bytes(),
+        ]);
+
+        let mut path = cache_dir()?;
+        path.push(format!("{source}-{key:016x}.bin"));
+        Some(Self { path, head })
+    }
+
+    pub fn read(&self, ttl: Duration) -> Option<Cached> {
+        let bytes = fs::read(&self.path).ok()?;
+        let entry: CacheEntry = bitcode::decode(&bytes).ok()?;
+        if entry.head != self.head {
+            return None;
+        }
+
+        if now().saturating_sub(entry.created_at) < ttl.as_secs() {
+            Some(Cached::Fresh(entry.bytes))
+        } else {
+            Some(Cached::Stale(entry.bytes))
+        }
+    }
+
+    pub fn write(&self, bytes: Option<&[u8]>) {
+        let entry = CacheEntry {
+            created_at: now(),
+            head: self.head.clone(),
+            bytes: bytes.map(<[u8]>::to_vec),
+        };
+
+        let Some(dir) = self.path.parent() else {
+            return;
+        };
+        if fs::create_dir_all(dir).is_err() {
+            return;
+        }
+
+        // Write to a temporary file first so that readers never see a partial entry.
+        let tmp_path = self
+            .path
+            .with_extension(format!("{}.tmp", std::process::id()));
+        let written = File::create(&tmp_path)
+            .and_then(|mut file| file.write_all(&bitcode::encode(&entry)))
+            .and_then(|_| fs::rename(&tmp_path, &self.path));
+        if written.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+    }
+
+    /// Removes the entries of the same source that have not been written for a long time.
+    pub fn prune(&self) {
+        let Some(dir) = self.path.parent() else {
+            return;
+        };
+        let Some(prefix) = self
+            .path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split_once('-'))
+            .map(|(source, _)| format!("{source}-"))
+        else {
+            return;
+        };
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !name.starts_with(&prefix) || !name.ends_with(".bin") {
+                continue;
+            }
+
+            let is_expired = entry
+                .metadata()
+                .and_then(|meta| meta.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|elapsed| elapsed > ENTRY_MAX_AGE);
+            if is_expired {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+
+    fn lock_path(&self) -> PathBuf {
+        self.path.with_extension("lock")
+    }
+
+    /// Takes the refresh lock, returning `false` if another refresh is already running.
+    pub fn try_lock_refresh(&self) -> bool {
+        let lock_path = self.lock_path();
+
+        let is_expired = fs::metadata(&lock_path)
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|elapsed| elapsed > REFRESH_LOCK_TIMEOUT);
+        if is_expired {
+            let _ = fs::remove_file(&lock_path);
+        }
+
+        if let Some(dir) = lock_path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&lock_path)
+            .is_ok()
+    }
+
+    pub fn unlock_refresh(&self) {
+        let _ = fs::remove_file(self.lock_path());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_write() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cache = Cache {
+            path: dir.path().join("croque/gh-0123456789abcdef.bin"),
+            head: Some("0123456789abcdef0123456789abcdef01234567".to_string()),
+        };
+
+        assert_eq!(cache.read(Duration::from_secs(60)), None);
+
+        cache.write(Some(b"data"));
+        assert_eq!(
+            cache.read(Duration::from_secs(60)),
+            Some(Cached::Fresh(Some(b"data".to_vec())))
+        );
+        assert_eq!(
+            cache.read(Duration::ZERO),
+            Some(Cached::Stale(Some(b"data".to_vec())))
+        );
+
+        cache.write(None);
+        assert_eq!(
+            cache.read(Duration::from_secs(60)),
+            Some(Cached::Fresh(None))
+        );
+    }
+
+    #[test]
+    fn test_read_other_head() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("croque/gh-0123456789abcdef.bin");
+        let cache = Cache {
+            path: path.clone(),
+            head: Some("0123456789abcdef0123456789abcdef01234567".to_string()),
+        };
+        cache.write(Some(b"data"));
+
+        let cache = Cache {
+            path,
+            head: Some("89abcdef0123456789abcdef0123456789abcdef".to_string()),
+        };
+        assert_eq!(cache.read(Duration::from_secs(60)), None);
+    }
+
+    #[test]
+    fn test_prune() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cache_dir = dir.path().join("croque");
+        let cache = Cache {
+            path: cache_dir.join("gh-0123456789abcdef.bin"),
+            head: None,
+        };
+        cache.write(Some(b"data"));
+
+        let old = SystemTime::now() - ENTRY_MAX_AGE - Duration::from_secs(60);
+        for name in ["gh-fedcba9876543210.bin", "glab-fedcba9876543210.bin"] {
+            File::create(cache_dir.join(name))
+                .unwrap()
+                .set_modified(old)
+                .unwrap();
+        }
+
+        cache.prune();
+        assert!(cache_dir.join("gh-0123456789abcdef.bin").exists());
+        assert!(!cache_dir.join("gh-fedcba9876543210.bin").exists());
+        assert!(cache_dir.join("glab-fedcba9876543210.bin").exists());
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(&[]), FNV_OFFSET_BASIS);
+        assert_eq!(fnv1a(&[b"a"]), 0x089b_e207_b544_f1e4);
+        assert_ne!(fnv1a(&[b"ab", b"c"]), fnv1a(&[b"a", b"bc"]));
+    }
+
+    #[test]
+    fn test_refresh_lock() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cache = Cache {
+            path: dir.path().join("croque/gh-0123456789abcdef.bin"),
+            head: Some("0123456789abcdef0123456789abcdef01234567".to_string()),
+        };
+
+        assert!(cache.try_lock_refresh());
+        assert!(!cache.try_lock_refresh());
+
+        cache.unlock_refresh();
+        assert!(cache.try_lock_refresh());
+    }
+}
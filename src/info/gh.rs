@@ -1,5 +1,6 @@
-use std::{process::Command, thread};
+use std::{process::Command, thread, time::Duration};
 
+use super::process::{TimedOut, output_with_timeout};
 use bitcode::{Decode, Encode};
 use git2::Repository;
 use serde::Deserialize;
@@ -8,6 +9,7 @@ use serde::Deserialize;
 pub struct GhInfo {
     pub pull_request: Option<PullRequest>,
     pub actions: Option<Actions>,
+    pub timed_out: bool,
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -17,6 +19,7 @@ pub struct PullRequest {
     pub comments: i32,
     pub is_draft: bool,
     pub is_approved: bool,
+    pub url: String,
 }
 
 #[derive(Debug, Encode, Decode, Deserialize)]
@@ -41,20 +44,22 @@ pub enum ActionsStatus {
     Skipped,
 }
 
-fn load_pull_request() -> Option<PullRequest> {
-    let output = Command::new("gh")
-        .args([
+fn load_pull_request(timeout: Duration) -> Result<Option<PullRequest>, TimedOut> {
+    let output = output_with_timeout(
+        Command::new("gh").args([
             "pr",
             "view",
-            "--json=number,state,comments,reviews,reviewDecision,isDraft",
-        ])
-        .output()
-        .ok()?;
-
-    if !output.status.success() {
-        return None;
-    }
+            "--json=number,state,comments,reviews,reviewDecision,isDraft,url",
+        ]),
+        timeout,
+    )?;
+
+    Ok(output
+        .filter(|output| output.status.success())
+        .and_then(|output| parse_pull_request(&output.stdout)))
+}
 
+fn parse_pull_request(stdout: &[u8]) -> Option<PullRequest> {
     #[derive(Debug, Deserialize)]
     struct PrComment {}
 
@@ -71,14 +76,16 @@ fn load_pull_request() -> Option<PullRequest> {
         review_decision: String,
         #[serde(rename = "isDraft")]
         is_draft: bool,
+        url: String,
     }
 
-    let result: PrResult = serde_json::from_slice(&output.stdout).ok()?;
+    let result: PrResult = serde_json::from_slice(stdout).ok()?;
     let number = result.number;
     let state = result.state;
     let comments = (result.comments.len() + result.reviews.len()) as i32;
     let is_draft = result.is_draft;
     let is_approved = result.review_decision == "APPROVED";
+    let url = result.url;
 
     Some(PullRequest {
         number,
@@ -86,29 +93,39 @@ fn load_pull_request() -> Option<PullRequest> {
         comments,
         is_draft,
         is_approved,
+        url,
     })
 }
 
-fn load_actions() -> Option<Actions> {
+fn head_oid() -> Option<String> {
     let current_dir = std::env::current_dir().ok()?;
     let repo = Repository::discover(current_dir).ok()?;
     let head = repo.head().ok()?.target()?.to_string();
+    Some(head)
+}
 
-    let output = Command::new("gh")
-        .args(["run", "list", "--commit", &head, "--json=conclusion"])
-        .output()
-        .ok()?;
+fn load_actions(timeout: Duration) -> Result<Option<Actions>, TimedOut> {
+    let Some(head) = head_oid() else {
+        return Ok(None);
+    };
 
-    if !output.status.success() {
-        return None;
-    }
+    let output = output_with_timeout(
+        Command::new("gh").args(["run", "list", "--commit", &head, "--json=conclusion"]),
+        timeout,
+    )?;
+
+    Ok(output
+        .filter(|output| output.status.success())
+        .and_then(|output| parse_actions(&output.stdout)))
+}
 
+fn parse_actions(stdout: &[u8]) -> Option<Actions> {
     #[derive(Debug, Deserialize)]
     pub struct WorkflowRun {
         conclusion: Option<String>,
     }
 
-    let runs: Vec<WorkflowRun> = serde_json::from_slice(&output.stdout).ok()?;
+    let runs: Vec<WorkflowRun> = serde_json::from_slice(stdout).ok()?;
 
     fn get_actions_status(runs: &[WorkflowRun]) -> Option<ActionsStatus> {
         let mut in_progress: i32 = 0;
@@ -147,15 +164,17 @@ fn load_actions() -> Option<Actions> {
     Some(Actions { status })
 }
 
-pub fn load_gh_info() -> Option<GhInfo> {
-    let pull_request_handle = thread::spawn(load_pull_request);
-    let actions_handle = thread::spawn(load_actions);
+pub fn load_gh_info(timeout: Duration) -> Option<GhInfo> {
+    let pull_request_handle = thread::spawn(move || load_pull_request(timeout));
+    let actions_handle = thread::spawn(move || load_actions(timeout));
 
-    let pull_request = pull_request_handle.join().ok().flatten();
-    let actions = actions_handle.join().ok().flatten();
+    let pull_request = pull_request_handle.join().ok()?;
+    let actions = actions_handle.join().ok()?;
+    let timed_out = pull_request.is_err() || actions.is_err();
 
     Some(GhInfo {
-        pull_request,
-        actions,
+        pull_request: pull_request.ok().flatten(),
+        actions: actions.ok().flatten(),
+        timed_out,
     })
 }
@@ -1,14 +1,27 @@
 use bitcode::{Decode, Encode};
-use git2::{Reference, Repository, Status, StatusOptions};
+use git2::{
+    AttrCheckFlags, DiffOptions, Pathspec, PathspecFlags, Reference, Repository, RepositoryState,
+    Status, StatusOptions, Worktree,
+};
+use std::{fs, io::Read, path::Path};
 
 #[derive(Debug, Encode, Decode)]
 pub struct GitInfo {
     pub workdir: Option<String>,
+    pub worktree: Option<String>,
+    pub superproject: Option<String>,
     pub head: Head,
     pub working_tree: WorkingTreeStatus,
     pub upstream: Option<UpstreamStatus>,
+    pub base: Option<AheadBehind>,
     pub remotes: Vec<RemoteStatus>,
     pub user: Option<String>,
+    pub operation: Option<OperationStatus>,
+    pub stash: u32,
+    pub diff: Option<DiffStats>,
+    pub commit: Option<CommitInfo>,
+    pub lfs: Option<LfsStatus>,
+    pub sparse_checkout: bool,
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -18,17 +31,17 @@ pub enum Head {
     Commit(String),
 }
 
-#[derive(Debug, Encode, Decode)]
+#[derive(Debug, Default, Encode, Decode)]
 pub struct WorkingTreeStatus {
-    unstaged_new: u32,
-    unstaged_deleted: u32,
-    unstaged_changed: u32,
-    unstaged_renamed: u32,
-    staged_new: u32,
-    staged_deleted: u32,
-    staged_changed: u32,
-    staged_renamed: u32,
-    conflicted: u32,
+    pub unstaged_new: u32,
+    pub unstaged_deleted: u32,
+    pub unstaged_changed: u32,
+    pub unstaged_renamed: u32,
+    pub staged_new: u32,
+    pub staged_deleted: u32,
+    pub staged_changed: u32,
+    pub staged_renamed: u32,
+    pub conflicted: u32,
 }
 
 impl WorkingTreeStatus {
@@ -67,8 +80,17 @@ impl WorkingTreeStatus {
     }
 }
 
-#[derive(Debug, Encode, Decode)]
-pub struct UpstreamStatus {
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub enum UpstreamStatus {
+    /// The branch has no upstream configured.
+    Missing,
+    /// The upstream is configured but no longer exists, e.g. after it was deleted on the remote.
+    Gone,
+    Tracking(AheadBehind),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct AheadBehind {
     pub ahead: u32,
     pub behind: u32,
 }
@@ -78,6 +100,71 @@ pub struct RemoteStatus {
     pub url: String,
 }
 
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct LfsStatus {
+    /// Whether some LFS files are still pointers, i.e. their content has not been downloaded.
+    pub unsmudged: bool,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct CommitInfo {
+    /// Seconds since the epoch.
+    pub time: i64,
+    pub author: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct DiffStats {
+    pub insertions: u32,
+    pub deletions: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub enum Operation {
+    Merge,
+    Revert,
+    CherryPick,
+    Bisect,
+    Rebase,
+    ApplyMailbox,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct OperationStatus {
+    pub operation: Operation,
+    pub progress: Option<OperationProgress>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct OperationProgress {
+    pub current: u32,
+    pub total: u32,
+}
+
+fn worktree_name(repo: &Repository) -> Option<String> {
+    if !repo.is_worktree() {
+        return None;
+    }
+
+    let worktree = Worktree::open_from_repository(repo).ok()?;
+    worktree.name().ok()?.map(str::to_string)
+}
+
+fn superproject_workdir(repo: &Repository) -> Option<String> {
+    let workdir = repo.workdir()?;
+    // Submodules are checked out with a `.git` file pointing into the superproject.
+    if !workdir.join(".git").is_file() {
+        return None;
+    }
+
+    let superproject = Repository::discover(workdir.parent()?).ok()?;
+    let superproject_workdir = superproject.workdir()?;
+    let submodule_path = workdir.strip_prefix(superproject_workdir).ok()?;
+    superproject.find_submodule(submodule_path.to_str()?).ok()?;
+
+    Some(superproject_workdir.to_string_lossy().to_string())
+}
+
 fn head_status(repo: &Repository, head_ref: &Option<Reference>) -> Head {
     let head_ref = match head_ref {
         Some(head_ref) => head_ref,
@@ -167,18 +254,41 @@ fn working_tree_status(repo: &Repository) -> WorkingTreeStatus {
 }
 
 fn upstream_status(repo: &Repository, head_ref: &Reference) -> Option<UpstreamStatus> {
-    let branch_name = head_ref.shorthand().ok()?;
-    let local_branch = repo
-        .find_branch(branch_name, git2::BranchType::Local)
-        .ok()?;
-    let upstream_branch = local_branch.upstream().ok()?;
+    if !head_ref.is_branch() {
+        return None;
+    }
+
+    let Ok(upstream_name) = repo.branch_upstream_name(head_ref.name().ok()?) else {
+        return Some(UpstreamStatus::Missing);
+    };
+    let Ok(upstream_ref) = repo.find_reference(upstream_name.as_str().ok()?) else {
+        return Some(UpstreamStatus::Gone);
+    };
 
     let local_oid = head_ref.target()?;
-    let upstream_oid = upstream_branch.get().target()?;
+    let upstream_oid = upstream_ref.target()?;
 
     let (ahead, behind) = repo.graph_ahead_behind(local_oid, upstream_oid).ok()?;
 
-    Some(UpstreamStatus {
+    Some(UpstreamStatus::Tracking(AheadBehind {
+        ahead: ahead as u32,
+        behind: behind as u32,
+    }))
+}
+
+fn base_status(
+    repo: &Repository,
+    head_ref: &Reference,
+    base_branches: &[String],
+) -> Option<AheadBehind> {
+    let base_oid = base_branches
+        .iter()
+        .find_map(|name| repo.resolve_reference_from_short_name(name).ok()?.target())?;
+    let head_oid = head_ref.target()?;
+
+    let (ahead, behind) = repo.graph_ahead_behind(head_oid, base_oid).ok()?;
+
+    Some(AheadBehind {
         ahead: ahead as u32,
         behind: behind as u32,
     })
@@ -200,16 +310,236 @@ fn remote_statuses(repo: &Repository) -> Vec<RemoteStatus> {
         .collect()
 }
 
+const LFS_POINTER_HEADER: &[u8] = b"version https://git-lfs.github.com/spec/v1";
+
+/// LFS pointer files are small text files, so larger ones must have been smudged.
+const LFS_POINTER_MAX_SIZE: u32 = 1024;
+
+fn is_lfs_pointer(path: &Path) -> bool {
+    let mut header = [0; LFS_POINTER_HEADER.len()];
+    fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .map(|_| header == LFS_POINTER_HEADER)
+        .unwrap_or(false)
+}
+
+/// Converts the `filter=lfs` patterns of an attributes file in `dir` into pathspecs.
+/// The pathspecs may match more paths than the patterns, but never fewer.
+fn lfs_pathspecs(attributes: &str, dir: &str) -> Vec<String> {
+    attributes
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let pattern = fields
+                .next()
+                .filter(|pattern| !pattern.starts_with('#') && !pattern.starts_with("[attr]"))?;
+            fields.any(|attr| attr == "filter=lfs").then_some(pattern)
+        })
+        .flat_map(|pattern| {
+            let pattern = pattern.trim_end_matches('/');
+            if pattern.contains('/') {
+                vec![format!("{dir}{}", pattern.trim_start_matches('/'))]
+            } else {
+                // Patterns without a slash match at any depth.
+                vec![format!("{dir}{pattern}"), format!("{dir}*/{pattern}")]
+            }
+        })
+        .collect()
+}
+
+fn lfs_status(repo: &Repository, max_files: usize) -> Option<LfsStatus> {
+    let workdir = repo.workdir()?;
+    let index = repo.index().ok()?;
+
+    let mut pathspecs = fs::read_to_string(repo.path().join("info/attributes"))
+        .map(|attributes| lfs_pathspecs(&attributes, ""))
+        .unwrap_or_default();
+    let attributes_files = Pathspec::new([".gitattributes", "*/.gitattributes"]).ok()?;
+    for path in attributes_files
+        .match_index(&index, PathspecFlags::DEFAULT)
+        .ok()?
+        .entries()
+    {
+        let path = String::from_utf8_lossy(path);
+        let Some(dir) = path
+            .strip_suffix(".gitattributes")
+            .filter(|dir| dir.is_empty() || dir.ends_with('/'))
+        else {
+            continue;
+        };
+        if let Ok(attributes) = fs::read_to_string(workdir.join(&*path)) {
+            pathspecs.extend(lfs_pathspecs(&attributes, dir));
+        }
+    }
+    if pathspecs.is_empty() {
+        return None;
+    }
+
+    // Only the paths matching an LFS pattern are checked, as reading the attributes of every path is slow.
+    let lfs_files = Pathspec::new(&pathspecs).ok()?;
+    let unsmudged = lfs_files
+        .match_index(&index, PathspecFlags::DEFAULT)
+        .ok()?
+        .entries()
+        .filter_map(|path| index.get_path(Path::new(std::str::from_utf8(path).ok()?), 0))
+        .filter(|entry| entry.file_size < LFS_POINTER_MAX_SIZE)
+        .take(max_files)
+        .filter_map(|entry| String::from_utf8(entry.path).ok())
+        .filter(|path| {
+            repo.get_attr(Path::new(path), "filter", AttrCheckFlags::FILE_THEN_INDEX)
+                .ok()
+                .flatten()
+                == Some("lfs")
+        })
+        .any(|path| is_lfs_pointer(&workdir.join(path)));
+
+    Some(LfsStatus { unsmudged })
+}
+
+fn sparse_checkout(repo: &Repository) -> bool {
+    repo.config()
+        .and_then(|config| config.get_bool("core.sparseCheckout"))
+        .unwrap_or(false)
+}
+
+fn commit_info(head_ref: &Reference) -> Option<CommitInfo> {
+    let commit = head_ref.peel_to_commit().ok()?;
+    let author = commit.author();
+
+    Some(CommitInfo {
+        time: commit.time().seconds(),
+        author: String::from_utf8_lossy(author.name_bytes()).to_string(),
+    })
+}
+
+fn diff_stats(repo: &Repository, max_files: usize) -> Option<DiffStats> {
+    let head_tree = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+
+    let mut diff_options = DiffOptions::new();
+    diff_options.ignore_submodules(true);
+
+    let diff = repo
+        .diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut diff_options))
+        .ok()?;
+
+    // Line statistics need the content of every changed file, so give up on huge diffs.
+    if diff.deltas().len() > max_files {
+        return None;
+    }
+
+    let stats = diff.stats().ok()?;
+
+    Some(DiffStats {
+        insertions: stats.insertions() as u32,
+        deletions: stats.deletions() as u32,
+    })
+}
+
+fn stash_count(repo: &mut Repository) -> u32 {
+    let mut count = 0;
+    let _ = repo.stash_foreach(|_, _, _| {
+        count += 1;
+        true
+    });
+    count
+}
+
+fn read_progress(dir: &Path, current_file: &str, total_file: &str) -> Option<OperationProgress> {
+    let read_number = |file: &str| -> Option<u32> {
+        fs::read_to_string(dir.join(file)).ok()?.trim().parse().ok()
+    };
+
+    Some(OperationProgress {
+        current: read_number(current_file)?,
+        total: read_number(total_file)?,
+    })
+}
+
+fn operation_status(repo: &Repository) -> Option<OperationStatus> {
+    let git_dir = repo.path();
+
+    let (operation, progress) = match repo.state() {
+        RepositoryState::Clean => return None,
+        RepositoryState::Merge => (Operation::Merge, None),
+        RepositoryState::Revert | RepositoryState::RevertSequence => (Operation::Revert, None),
+        RepositoryState::CherryPick | RepositoryState::CherryPickSequence => {
+            (Operation::CherryPick, None)
+        }
+        RepositoryState::Bisect => (Operation::Bisect, None),
+        RepositoryState::Rebase
+        | RepositoryState::RebaseInteractive
+        | RepositoryState::RebaseMerge => {
+            // The merge backend keeps its progress in `rebase-merge`, the apply backend in `rebase-apply`.
+            let progress = read_progress(&git_dir.join("rebase-merge"), "msgnum", "end")
+                .or_else(|| read_progress(&git_dir.join("rebase-apply"), "next", "last"));
+            (Operation::Rebase, progress)
+        }
+        RepositoryState::ApplyMailbox | RepositoryState::ApplyMailboxOrRebase => {
+            let progress = read_progress(&git_dir.join("rebase-apply"), "next", "last");
+            (Operation::ApplyMailbox, progress)
+        }
+    };
+
+    Some(OperationStatus {
+        operation,
+        progress,
+    })
+}
+
 fn user_name(repo: &Repository) -> Option<String> {
     let config = repo.config().ok()?;
     config.get_string("user.name").ok()
 }
 
-pub fn load_git_info() -> Option<GitInfo> {
+#[derive(Debug, Default)]
+pub struct GitInfoOptions<'a> {
+    /// Diff statistics are only computed when this is given.
+    pub diff_max_files: Option<usize>,
+
+    /// Candidates for the base branch; the first one that exists is used.
+    /// Nothing is compared when this is empty.
+    pub base_branches: &'a [String],
+
+    /// Whether to detect an in-progress rebase, merge, cherry-pick, bisect or revert.
+    pub operation: bool,
+
+    /// Whether to count the stash entries.
+    pub stash: bool,
+
+    /// Whether to look up the name of a linked worktree.
+    pub worktree: bool,
+
+    /// Whether to look up the superproject of a submodule.
+    pub superproject: bool,
+
+    /// Whether to read the time and author of the last commit.
+    pub commit: bool,
+
+    /// LFS pointers are only looked for among this many files matching an LFS pattern when this is given.
+    pub lfs_max_files: Option<usize>,
+
+    /// Whether to check if sparse checkout is enabled.
+    pub sparse_checkout: bool,
+}
+
+/// Loads the status of the repository containing the current directory.
+pub fn load_git_info(options: &GitInfoOptions) -> Option<GitInfo> {
     let current_dir = std::env::current_dir().ok()?;
-    let repo = Repository::discover(current_dir).ok()?;
+    let mut repo = Repository::discover(current_dir).ok()?;
 
     let workdir = repo.workdir().map(|p| p.to_string_lossy().to_string());
+    let worktree = options.worktree.then(|| worktree_name(&repo)).flatten();
+    let superproject = options
+        .superproject
+        .then(|| superproject_workdir(&repo))
+        .flatten();
+
+    // Walking the stash needs `&mut Repository`, so count it before borrowing HEAD.
+    let stash = if options.stash {
+        stash_count(&mut repo)
+    } else {
+        0
+    };
 
     let head_ref = repo.head().ok();
 
@@ -218,15 +548,242 @@ pub fn load_git_info() -> Option<GitInfo> {
     let upstream = head_ref
         .as_ref()
         .and_then(|head_ref| upstream_status(&repo, head_ref));
+    let base = head_ref
+        .as_ref()
+        .and_then(|head_ref| base_status(&repo, head_ref, options.base_branches));
+    let commit = head_ref
+        .as_ref()
+        .filter(|_| options.commit)
+        .and_then(commit_info);
     let remotes = remote_statuses(&repo);
+    let lfs = options
+        .lfs_max_files
+        .and_then(|max_files| lfs_status(&repo, max_files));
+    let sparse_checkout = options.sparse_checkout && sparse_checkout(&repo);
     let user = user_name(&repo);
+    let operation = options.operation.then(|| operation_status(&repo)).flatten();
+    let diff = options
+        .diff_max_files
+        .and_then(|max_files| diff_stats(&repo, max_files));
 
     Some(GitInfo {
         workdir,
+        worktree,
+        superproject,
         head,
         working_tree,
         upstream,
+        base,
         remotes,
         user,
+        operation,
+        stash,
+        diff,
+        commit,
+        lfs,
+        sparse_checkout,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_status() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        assert_eq!(operation_status(&repo), None);
+
+        let rebase_dir = repo.path().join("rebase-merge");
+        fs::create_dir(&rebase_dir).unwrap();
+        fs::write(rebase_dir.join("interactive"), "").unwrap();
+        fs::write(rebase_dir.join("msgnum"), "2\n").unwrap();
+        fs::write(rebase_dir.join("end"), "5\n").unwrap();
+        assert_eq!(
+            operation_status(&repo),
+            Some(OperationStatus {
+                operation: Operation::Rebase,
+                progress: Some(OperationProgress {
+                    current: 2,
+                    total: 5
+                }),
+            })
+        );
+        fs::remove_dir_all(&rebase_dir).unwrap();
+
+        fs::write(repo.path().join("MERGE_HEAD"), "").unwrap();
+        assert_eq!(
+            operation_status(&repo),
+            Some(OperationStatus {
+                operation: Operation::Merge,
+                progress: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_stats() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        assert_eq!(
+            diff_stats(&repo, 10),
+            Some(DiffStats {
+                insertions: 0,
+                deletions: 0
+            })
+        );
+
+        fs::write(dir.path().join("README"), "one\ntwo\n").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README")).unwrap();
+        index.write().unwrap();
+        assert_eq!(
+            diff_stats(&repo, 10),
+            Some(DiffStats {
+                insertions: 2,
+                deletions: 0
+            })
+        );
+
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("John Doe", "john.doe@example.com").unwrap();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            "Initial commit",
+            &tree,
+            &[],
+        )
+        .unwrap();
+        fs::write(dir.path().join("README"), "one\nthree\nfour\n").unwrap();
+        assert_eq!(
+            diff_stats(&repo, 10),
+            Some(DiffStats {
+                insertions: 2,
+                deletions: 1
+            })
+        );
+        assert_eq!(diff_stats(&repo, 0), None);
+    }
+
+    #[test]
+    fn test_upstream_status() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let signature = git2::Signature::now("John Doe", "john.doe@example.com").unwrap();
+        let tree = repo
+            .find_tree(repo.index().unwrap().write_tree().unwrap())
+            .unwrap();
+        let oid = repo
+            .commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                "Initial commit",
+                &tree,
+                &[],
+            )
+            .unwrap();
+        let head_ref = repo.head().unwrap();
+        let branch = head_ref.shorthand().unwrap().to_string();
+        assert_eq!(
+            upstream_status(&repo, &head_ref),
+            Some(UpstreamStatus::Missing)
+        );
+
+        repo.remote("origin", "https://example.com/repo.git")
+            .unwrap();
+        let mut config = repo.config().unwrap();
+        config
+            .set_str(&format!("branch.{branch}.remote"), "origin")
+            .unwrap();
+        config
+            .set_str(
+                &format!("branch.{branch}.merge"),
+                &format!("refs/heads/{branch}"),
+            )
+            .unwrap();
+        assert_eq!(
+            upstream_status(&repo, &head_ref),
+            Some(UpstreamStatus::Gone)
+        );
+
+        repo.reference(&format!("refs/remotes/origin/{branch}"), oid, false, "")
+            .unwrap();
+        assert_eq!(
+            upstream_status(&repo, &head_ref),
+            Some(UpstreamStatus::Tracking(AheadBehind {
+                ahead: 0,
+                behind: 0
+            }))
+        );
+    }
+
+    #[test]
+    fn test_lfs_pathspecs() {
+        assert_eq!(
+            lfs_pathspecs(
+                "# comment\n\
+                 *.bin filter=lfs diff=lfs merge=lfs -text\n\
+                 /data/ filter=lfs\n\
+                 *.txt text\n",
+                "media/",
+            ),
+            ["media/*.bin", "media/*/*.bin", "media/data"]
+        );
+        assert!(lfs_pathspecs("*.txt text\n", "").is_empty());
+    }
+
+    #[test]
+    fn test_lfs_status() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        assert_eq!(lfs_status(&repo, 1000), None);
+        assert!(!sparse_checkout(&repo));
+
+        // Many files come before the LFS one in the index, and only the attributes of a subdirectory use LFS.
+        let mut index = repo.index().unwrap();
+        let docs = dir.path().join("docs");
+        fs::create_dir(&docs).unwrap();
+        for i in 0..1100 {
+            fs::write(docs.join(format!("{i}.txt")), "").unwrap();
+            index.add_path(Path::new(&format!("docs/{i}.txt"))).unwrap();
+        }
+        let videos = dir.path().join("media/videos");
+        fs::create_dir_all(&videos).unwrap();
+        fs::write(
+            dir.path().join("media/.gitattributes"),
+            "*.bin filter=lfs diff=lfs merge=lfs -text\n",
+        )
+        .unwrap();
+        fs::write(
+            videos.join("data.bin"),
+            "version https://git-lfs.github.com/spec/v1\n\
+             oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393\n\
+             size 12345\n",
+        )
+        .unwrap();
+        index.write().unwrap();
+        assert_eq!(lfs_status(&repo, 1000), None);
+
+        index.add_path(Path::new("media/.gitattributes")).unwrap();
+        index.add_path(Path::new("media/videos/data.bin")).unwrap();
+        index.write().unwrap();
+        assert_eq!(lfs_status(&repo, 1000), Some(LfsStatus { unsmudged: true }));
+        assert_eq!(lfs_status(&repo, 0), Some(LfsStatus { unsmudged: false }));
+
+        fs::write(videos.join("data.bin"), vec![0; 12345]).unwrap();
+        assert_eq!(
+            lfs_status(&repo, 1000),
+            Some(LfsStatus { unsmudged: false })
+        );
+
+        repo.config()
+            .unwrap()
+            .set_bool("core.sparseCheckout", true)
+            .unwrap();
+        assert!(sparse_checkout(&repo));
+    }
+}
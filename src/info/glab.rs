@@ -1,10 +1,12 @@
+use super::process::{TimedOut, output_with_timeout};
 use bitcode::{Decode, Encode};
 use serde::Deserialize;
-use std::process::Command;
+use std::{process::Command, time::Duration};
 
 #[derive(Debug, Encode, Decode)]
 pub struct GlabInfo {
-    pub merge_request: MergeRequest,
+    pub merge_request: Option<MergeRequest>,
+    pub timed_out: bool,
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -15,6 +17,7 @@ pub struct MergeRequest {
     pub pipeline: MrPipelineState,
     pub is_draft: bool,
     pub is_approved: bool,
+    pub url: String,
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -34,16 +37,18 @@ pub enum MrPipelineState {
     Canceled,
 }
 
-fn load_merge_request() -> Option<MergeRequest> {
-    let output = Command::new("glab")
-        .args(["mr", "view", "--output=json", "--comments"])
-        .output()
-        .ok()?;
+fn load_merge_request(timeout: Duration) -> Result<Option<MergeRequest>, TimedOut> {
+    let output = output_with_timeout(
+        Command::new("glab").args(["mr", "view", "--output=json", "--comments"]),
+        timeout,
+    )?;
 
-    if !output.status.success() {
-        return None;
-    }
+    Ok(output
+        .filter(|output| output.status.success())
+        .and_then(|output| parse_merge_request(&output.stdout)))
+}
 
+fn parse_merge_request(stdout: &[u8]) -> Option<MergeRequest> {
     #[derive(Debug, Deserialize)]
     struct MrComment {
         body: String,
@@ -62,11 +67,12 @@ fn load_merge_request() -> Option<MergeRequest> {
         draft: bool,
         user_notes_count: i32,
         pipeline: Option<MrPipeline>,
+        web_url: String,
         #[serde(rename = "Notes")]
         notes: Vec<MrComment>,
     }
 
-    let result: MrResult = serde_json::from_slice(&output.stdout).ok()?;
+    let result: MrResult = serde_json::from_slice(stdout).ok()?;
 
     Some(MergeRequest {
         number: result.iid,
@@ -92,11 +98,18 @@ fn load_merge_request() -> Option<MergeRequest> {
             .notes
             .iter()
             .any(|note| note.system && note.body == "approved this merge request"),
+        url: result.web_url,
     })
 }
 
-pub fn load_glab_info() -> Option<GlabInfo> {
-    let merge_request = load_merge_request()?;
+pub fn load_glab_info(timeout: Duration) -> Option<GlabInfo> {
+    let (merge_request, timed_out) = match load_merge_request(timeout) {
+        Ok(merge_request) => (Some(merge_request?), false),
+        Err(TimedOut) => (None, true),
+    };
 
-    Some(GlabInfo { merge_request })
+    Some(GlabInfo {
+        merge_request,
+        timed_out,
+    })
 }
@@ -0,0 +1,131 @@
+use super::process::{TimedOut, output_with_timeout};
+use bitcode::{Decode, Encode};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct HgInfo {
+    pub branch: String,
+    pub bookmark: Option<String>,
+    pub working_tree: HgWorkingTreeStatus,
+    pub timed_out: bool,
+}
+
+#[derive(Debug, Default, PartialEq, Encode, Decode)]
+pub struct HgWorkingTreeStatus {
+    pub modified: bool,
+    pub untracked: bool,
+}
+
+impl HgWorkingTreeStatus {
+    pub fn is_dirty(&self) -> bool {
+        self.modified || self.untracked
+    }
+}
+
+fn find_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| dir.join(".hg").is_dir())
+        .map(Path::to_path_buf)
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    Some(content.trim().to_string()).filter(|content| !content.is_empty())
+}
+
+fn parse_status(stdout: &[u8]) -> HgWorkingTreeStatus {
+    let mut status = HgWorkingTreeStatus::default();
+
+    for line in stdout.split(|&b| b == b'\n') {
+        match line.first() {
+            Some(b'M' | b'A' | b'R' | b'!') => status.modified = true,
+            Some(b'?') => status.untracked = true,
+            _ => {}
+        }
+    }
+
+    status
+}
+
+fn working_tree_status(root: &Path, timeout: Duration) -> Result<HgWorkingTreeStatus, TimedOut> {
+    let output = output_with_timeout(
+        Command::new("hg")
+            .args(["status", "--color=never"])
+            .env("HGPLAIN", "1")
+            .current_dir(root),
+        timeout,
+    )?;
+
+    Ok(output
+        .filter(|output| output.status.success())
+        .map(|output| parse_status(&output.stdout))
+        .unwrap_or_default())
+}
+
+pub fn load_hg_info(timeout: Duration) -> Option<HgInfo> {
+    let current_dir = std::env::current_dir().ok()?;
+    let root = find_root(&current_dir)?;
+    let hg_dir = root.join(".hg");
+
+    // Reading the files directly is much faster than starting hg, which is a Python program.
+    let branch = read_trimmed(&hg_dir.join("branch")).unwrap_or_else(|| "default".to_string());
+    let bookmark = read_trimmed(&hg_dir.join("bookmarks.current"));
+    let (working_tree, timed_out) = match working_tree_status(&root, timeout) {
+        Ok(working_tree) => (working_tree, false),
+        Err(TimedOut) => (HgWorkingTreeStatus::default(), true),
+    };
+
+    Some(HgInfo {
+        branch,
+        bookmark,
+        working_tree,
+        timed_out,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(parse_status(b""), HgWorkingTreeStatus::default());
+        assert_eq!(
+            parse_status(b"M src/main.rs\n"),
+            HgWorkingTreeStatus {
+                modified: true,
+                untracked: false,
+            }
+        );
+        assert_eq!(
+            parse_status(b"! removed.txt\n? new.txt\n"),
+            HgWorkingTreeStatus {
+                modified: true,
+                untracked: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_hg_info() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let hg_dir = dir.path().join(".hg");
+        fs::create_dir(&hg_dir).unwrap();
+        let sub_dir = dir.path().join("src");
+        fs::create_dir(&sub_dir).unwrap();
+
+        assert_eq!(find_root(&sub_dir), Some(dir.path().to_path_buf()));
+        assert_eq!(read_trimmed(&hg_dir.join("branch")), None);
+
+        fs::write(hg_dir.join("branch"), "stable\n").unwrap();
+        assert_eq!(
+            read_trimmed(&hg_dir.join("branch")),
+            Some("stable".to_string())
+        );
+    }
+}
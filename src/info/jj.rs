@@ -0,0 +1,91 @@
+use super::process::output_with_timeout;
+use bitcode::{Decode, Encode};
+use std::{path::Path, process::Command, time::Duration};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct JjInfo {
+    pub change_id: String,
+    pub bookmarks: Vec<String>,
+    pub conflict: bool,
+}
+
+const TEMPLATE: &str = r#"change_id.shortest(8) ++ "\n" ++ local_bookmarks.map(|b| b.name()).join(" ") ++ "\n" ++ if(conflict, "conflict") ++ "\n""#;
+
+fn is_jj_workspace(dir: &Path) -> bool {
+    dir.ancestors().any(|dir| dir.join(".jj").is_dir())
+}
+
+fn parse_log(stdout: &[u8]) -> Option<JjInfo> {
+    let stdout = std::str::from_utf8(stdout).ok()?;
+    let mut lines = stdout.lines();
+
+    let change_id = lines.next().filter(|id| !id.is_empty())?.to_string();
+    let bookmarks = lines
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+    let conflict = lines.next() == Some("conflict");
+
+    Some(JjInfo {
+        change_id,
+        bookmarks,
+        conflict,
+    })
+}
+
+pub fn load_jj_info(timeout: Duration) -> Option<JjInfo> {
+    let current_dir = std::env::current_dir().ok()?;
+    // Avoid spawning jj outside of a workspace, which is the common case.
+    if !is_jj_workspace(&current_dir) {
+        return None;
+    }
+
+    // Snapshotting the working copy could take a while and write to the repo, so use the last snapshot.
+    let output = output_with_timeout(
+        Command::new("jj").args([
+            "log",
+            "--no-graph",
+            "--ignore-working-copy",
+            "--color=never",
+            "--revisions=@",
+            "--template",
+            TEMPLATE,
+        ]),
+        timeout,
+    )
+    .ok()??;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    parse_log(&output.stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log() {
+        assert_eq!(
+            parse_log(b"kmpvrszu\nmain feature\n\n"),
+            Some(JjInfo {
+                change_id: "kmpvrszu".to_string(),
+                bookmarks: vec!["main".to_string(), "feature".to_string()],
+                conflict: false,
+            })
+        );
+        assert_eq!(
+            parse_log(b"kmp\n\nconflict\n"),
+            Some(JjInfo {
+                change_id: "kmp".to_string(),
+                bookmarks: vec![],
+                conflict: true,
+            })
+        );
+        assert_eq!(parse_log(b""), None);
+    }
+}
@@ -1,8 +1,12 @@
 use base64::Engine;
 
+pub mod cache;
 pub mod gh;
 pub mod git;
 pub mod glab;
+pub mod hg;
+pub mod jj;
+pub mod process;
 
 fn base64_engine() -> impl Engine {
     base64::engine::general_purpose::GeneralPurpose::new(
@@ -0,0 +1,136 @@
+use std::{
+    io::Read,
+    process::{Child, Command, Output, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, PartialEq)]
+pub struct TimedOut;
+
+/// Converts a timeout in seconds from the config, treating invalid values as zero.
+pub fn timeout(secs: f64) -> Duration {
+    Duration::try_from_secs_f64(secs).unwrap_or_default()
+}
+
+/// Runs the command and collects its stdout, killing it if it does not exit within `timeout`.
+///
+/// Returns `Ok(None)` if the command could not be run at all (e.g. it is not installed).
+pub fn output_with_timeout(
+    cmd: &mut Command,
+    timeout: Duration,
+) -> Result<Option<Output>, TimedOut> {
+    // Run the command in its own process group, so that a timeout also stops the processes it started.
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(cmd, 0);
+
+    let mut child = match cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(_) => return Ok(None),
+    };
+
+    // Drain stdout concurrently so that the child never blocks on a full pipe.
+    let mut stdout = child.stdout.take();
+    let reader = thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(stdout) = stdout.as_mut() {
+            let _ = stdout.read_to_end(&mut buf);
+        }
+        buf
+    });
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                let stdout = reader.join().unwrap_or_default();
+                return Ok(Some(Output {
+                    status,
+                    stdout,
+                    stderr: Vec::new(),
+                }));
+            }
+            Ok(None) if Instant::now() < deadline => thread::sleep(POLL_INTERVAL),
+            Ok(None) => {
+                kill(&mut child);
+                let _ = child.wait();
+                return Err(TimedOut);
+            }
+            Err(_) => return Ok(None),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn kill(child: &mut Child) {
+    // SAFETY: kill(2) only takes integers. The group exists until the child is waited for.
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+    }
+}
+
+#[cfg(not(unix))]
+fn kill(child: &mut Child) {
+    let _ = child.kill();
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_with_timeout() {
+        let output = output_with_timeout(
+            Command::new("sh").args(["-c", "echo hello"]),
+            Duration::from_secs(5),
+        )
+        .unwrap()
+        .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"hello\n");
+
+        let output = output_with_timeout(
+            Command::new("sh").args(["-c", "exit 3"]),
+            Duration::from_secs(5),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(output.status.code(), Some(3));
+
+        let result = output_with_timeout(
+            Command::new("sh").args(["-c", "sleep 10"]),
+            Duration::from_millis(100),
+        );
+        assert_eq!(result, Err(TimedOut));
+
+        let result = output_with_timeout(
+            &mut Command::new("croque-command-not-found"),
+            Duration::from_secs(5),
+        );
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn test_output_with_timeout_kills_process_group() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file = dir.path().join("late");
+
+        let result = output_with_timeout(
+            Command::new("sh")
+                .args(["-c", "(sleep 0.5; echo late >\"$0\") & wait"])
+                .arg(&file),
+            Duration::from_millis(100),
+        );
+        assert_eq!(result, Err(TimedOut));
+
+        thread::sleep(Duration::from_secs(1));
+        assert!(!file.exists());
+    }
+}
@@ -0,0 +1,16 @@
+use super::{Context, Segment, SegmentBuilder};
+
+#[derive(Debug, Default)]
+pub struct ContinuationSegmentBuilder {}
+
+impl SegmentBuilder for ContinuationSegmentBuilder {
+    fn build(&self, ctx: &Context) -> Option<Segment> {
+        let config = &ctx.config.continuation;
+
+        Some(Segment {
+            content: config.content.clone(),
+            style: config.style.to_ansi(),
+            link: None,
+        })
+    }
+}
@@ -1,6 +1,7 @@
-use std::process::Command;
+use std::{process::Command, time::Duration};
 
 use super::{Context, Segment, SegmentBuilder};
+use crate::info::process::{TimedOut, output_with_timeout, timeout};
 use serde::Deserialize;
 
 #[derive(Debug)]
@@ -23,7 +24,17 @@ impl SegmentBuilder for DirenvSegmentBuilder {
         let config = &ctx.config.direnv;
         let icons = &config.icons;
 
-        let status: Status = load_direnv_status()?;
+        let status: Status = match load_direnv_status(timeout(config.timeout)) {
+            Ok(status) => status?,
+            Err(TimedOut) => {
+                let content = config.content.replace("{{.status}}", &icons.unknown);
+                return Some(Segment {
+                    content,
+                    style: config.found.style.to_ansi(),
+                    link: None,
+                });
+            }
+        };
         let _ = status.state.found_rc?;
 
         let loaded_rc = status.state.loaded_rc.as_ref();
@@ -43,6 +54,7 @@ impl SegmentBuilder for DirenvSegmentBuilder {
         Some(Segment {
             content,
             style: style.to_ansi(),
+            link: None,
         })
     }
 }
@@ -71,19 +83,12 @@ const DIRENV_ALLOWED: i32 = 0;
 // const DIRENV_NOT_ALLOWED: i32 = 1;
 const DIRENV_DENIED: i32 = 2;
 
-fn load_direnv_status() -> Option<Status> {
-    let output = Command::new("direnv")
-        .args(["status", "--json"])
-        .output()
-        .ok()?;
-
-    if !output.status.success() {
-        return None;
-    }
-
-    let status: Status = serde_json::from_slice(&output.stdout).ok()?;
+fn load_direnv_status(timeout: Duration) -> Result<Option<Status>, TimedOut> {
+    let output = output_with_timeout(Command::new("direnv").args(["status", "--json"]), timeout)?;
 
-    Some(status)
+    Ok(output
+        .filter(|output| output.status.success())
+        .and_then(|output| serde_json::from_slice(&output.stdout).ok()))
 }
 
 #[cfg(test)]
@@ -105,6 +110,8 @@ mod tests {
         for s in scenarios.iter() {
             let args = &SegmentArgs {
                 right: false,
+                transient: false,
+                continuation: false,
                 exit_status: 0,
                 duration: 0.0,
                 jobs: 0,
@@ -112,9 +119,11 @@ mod tests {
                 encoded_git_info: None,
                 encoded_gh_info: None,
                 encoded_glab_info: None,
+                encoded_jj_info: None,
+                encoded_hg_info: None,
                 shell: Shell::Zsh,
             };
-            let ctx = Context::new(&config, args, None, None, None);
+            let ctx = Context::new(&config, args, None, None, None, None, None);
 
             let target = DirenvSegmentBuilder::new();
 
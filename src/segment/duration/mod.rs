@@ -50,7 +50,11 @@ impl SegmentBuilder for DurationSegmentBuilder {
             .replace("{{.duration}}", &format_duration(duration));
         let style = config.style.to_ansi();
 
-        Some(Segment { content, style })
+        Some(Segment {
+            content,
+            style,
+            link: None,
+        })
     }
 }
 
@@ -142,6 +146,8 @@ mod tests {
         for s in scenarios {
             let args = SegmentArgs {
                 right: false,
+                transient: false,
+                continuation: false,
                 exit_status: 0,
                 duration: s.duration,
                 jobs: 0,
@@ -149,10 +155,12 @@ mod tests {
                 encoded_git_info: None,
                 encoded_gh_info: None,
                 encoded_glab_info: None,
+                encoded_jj_info: None,
+                encoded_hg_info: None,
                 shell: crate::shell::Shell::Zsh,
             };
 
-            let ctx = &Context::new(&config, &args, None, None, None);
+            let ctx = &Context::new(&config, &args, None, None, None, None, None);
 
             let target = DurationSegmentBuilder::default();
             let actual = target.build(ctx);
@@ -47,13 +47,32 @@ impl Default for GhActionsSegmentBuilder {
 impl SegmentBuilder for GhActionsSegmentBuilder {
     fn build(&self, ctx: &Context) -> Option<Segment> {
         let config = &ctx.config.gh_actions;
-        let actions = ctx.gh_info?.actions.as_ref()?;
+        let gh_info = ctx.gh_info?;
+        let Some(actions) = gh_info.actions.as_ref() else {
+            if !gh_info.timed_out {
+                return None;
+            }
+
+            let content = self
+                .replacer
+                .replace_all(&config.content, &[&config.icons.unknown]);
+            let style = config.skipped.style.to_ansi();
+            return Some(Segment {
+                content,
+                style,
+                link: None,
+            });
+        };
 
         let icon = self.icon(config, actions);
 
         let content = self.replacer.replace_all(&config.content, &[icon]);
         let style = self.style(config, actions).to_ansi();
 
-        Some(Segment { content, style })
+        Some(Segment {
+            content,
+            style,
+            link: None,
+        })
     }
 }
@@ -54,6 +54,25 @@ impl GhPullRequestSegmentBuilder {
         }
     }
 
+    fn build_unknown(&self, config: &GhPullRequestConfig) -> Segment {
+        let content = self.replacer.replace_all(
+            &config.content,
+            &[
+                config.icons.unknown_number.as_str(),
+                &format!(" {}", config.icons.unknown),
+                "",
+                "",
+            ],
+        );
+        let style = config.draft.style.to_ansi();
+
+        Segment {
+            content,
+            style,
+            link: None,
+        }
+    }
+
     fn style<'a>(&self, config: &'a GhPullRequestConfig, pr: &PullRequest) -> &'a Style {
         match (&pr.state, pr.is_draft) {
             (PullRequestState::Open, false) => &config.open.style,
@@ -73,7 +92,10 @@ impl Default for GhPullRequestSegmentBuilder {
 impl SegmentBuilder for GhPullRequestSegmentBuilder {
     fn build(&self, ctx: &Context) -> Option<Segment> {
         let config = &ctx.config.gh_pull_request;
-        let pr = &ctx.gh_info?.pull_request.as_ref()?;
+        let gh_info = ctx.gh_info?;
+        let Some(pr) = gh_info.pull_request.as_ref() else {
+            return gh_info.timed_out.then(|| self.build_unknown(config));
+        };
 
         let number = self.build_number(config, pr);
         let state = self.build_state(config, pr);
@@ -91,7 +113,12 @@ impl SegmentBuilder for GhPullRequestSegmentBuilder {
         );
 
         let style = self.style(config, pr).to_ansi();
+        let link = Some(pr.url.clone());
 
-        Some(Segment { content, style })
+        Some(Segment {
+            content,
+            style,
+            link,
+        })
     }
 }
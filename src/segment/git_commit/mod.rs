@@ -0,0 +1,87 @@
+use super::{Context, Segment, SegmentBuilder};
+use aho_corasick::AhoCorasick;
+use chrono::Utc;
+
+fn format_age(age: i64) -> String {
+    static MIN: i64 = 60;
+    static HOUR: i64 = 60 * MIN;
+    static DAY: i64 = 24 * HOUR;
+    static WEEK: i64 = 7 * DAY;
+    static MONTH: i64 = 30 * DAY;
+    static YEAR: i64 = 365 * DAY;
+
+    if age < MIN {
+        "just now".to_string()
+    } else if age < HOUR {
+        format!("{}m ago", age / MIN)
+    } else if age < DAY {
+        format!("{}h ago", age / HOUR)
+    } else if age < WEEK {
+        format!("{}d ago", age / DAY)
+    } else if age < MONTH {
+        format!("{}w ago", age / WEEK)
+    } else if age < YEAR {
+        format!("{}mo ago", age / MONTH)
+    } else {
+        format!("{}y ago", age / YEAR)
+    }
+}
+
+pub struct GitCommitSegmentBuilder<'a> {
+    replacer: AhoCorasick,
+    now: &'a dyn Fn() -> i64,
+}
+
+impl Default for GitCommitSegmentBuilder<'_> {
+    fn default() -> Self {
+        let replacer = AhoCorasick::new(["{{.commit_age}}", "{{.commit_author}}"]).unwrap();
+        Self {
+            replacer,
+            now: &|| Utc::now().timestamp(),
+        }
+    }
+}
+
+impl SegmentBuilder for GitCommitSegmentBuilder<'_> {
+    fn build(&self, ctx: &Context) -> Option<Segment> {
+        let config = &ctx.config.git_commit;
+        let commit = ctx.git_info?.commit.as_ref()?;
+
+        // The age is computed here rather than in `info::git` so that prepared data does not go stale.
+        let age = ((self.now)() - commit.time).max(0);
+
+        let content = self.replacer.replace_all(
+            &config.content,
+            &[format_age(age).as_str(), commit.author.as_str()],
+        );
+
+        let style = if age as u64 > config.stale_after {
+            &config.stale.style
+        } else {
+            &config.fresh.style
+        };
+
+        Some(Segment {
+            content,
+            style: style.to_ansi(),
+            link: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(0), "just now");
+        assert_eq!(format_age(59), "just now");
+        assert_eq!(format_age(60), "1m ago");
+        assert_eq!(format_age(3 * 60 * 60 + 59), "3h ago");
+        assert_eq!(format_age(2 * 24 * 60 * 60), "2d ago");
+        assert_eq!(format_age(15 * 24 * 60 * 60), "2w ago");
+        assert_eq!(format_age(90 * 24 * 60 * 60), "3mo ago");
+        assert_eq!(format_age(800 * 24 * 60 * 60), "2y ago");
+    }
+}
@@ -0,0 +1,37 @@
+use super::{Context, Segment, SegmentBuilder};
+use aho_corasick::AhoCorasick;
+
+#[derive(Debug)]
+pub struct GitDiffSegmentBuilder {
+    replacer: AhoCorasick,
+}
+
+impl Default for GitDiffSegmentBuilder {
+    fn default() -> Self {
+        let replacer = AhoCorasick::new(["{{.insertions}}", "{{.deletions}}"]).unwrap();
+        Self { replacer }
+    }
+}
+
+impl SegmentBuilder for GitDiffSegmentBuilder {
+    fn build(&self, ctx: &Context) -> Option<Segment> {
+        let config = &ctx.config.git_diff;
+
+        let diff = ctx.git_info?.diff.as_ref()?;
+        if diff.insertions == 0 && diff.deletions == 0 {
+            return None;
+        }
+
+        let content = self.replacer.replace_all(
+            &config.content,
+            &[diff.insertions.to_string(), diff.deletions.to_string()],
+        );
+        let style = config.style.to_ansi();
+
+        Some(Segment {
+            content,
+            style,
+            link: None,
+        })
+    }
+}
@@ -1,10 +1,14 @@
 use crate::{
-    config::git_status::{BranchAlias, GitStatusIcons, RemoteConfig},
-    info::git::{Head, RemoteStatus, UpstreamStatus, WorkingTreeStatus},
+    config::git_status::{BranchAlias, GitStatusIcons, RemoteConfig, WorkingTreeConfig},
+    info::git::{
+        AheadBehind, Head, LfsStatus, Operation, OperationStatus, RemoteStatus, UpstreamStatus,
+        WorkingTreeStatus,
+    },
 };
 use aho_corasick::AhoCorasick;
 use std::borrow::Cow;
 use std::fmt::Write;
+use std::path::Path;
 
 use super::{Context, Segment, SegmentBuilder};
 
@@ -18,8 +22,15 @@ impl GitStatusSegmentBuilder {
         let replacer = AhoCorasick::new([
             "{{.remote}}",
             "{{.head}}",
+            "{{.worktree}}",
+            "{{.submodule}}",
             "{{.working_tree}}",
             "{{.upstream}}",
+            "{{.base}}",
+            "{{.stash}}",
+            "{{.lfs}}",
+            "{{.sparse}}",
+            "{{.operation}}",
         ])
         .unwrap();
         Self { replacer }
@@ -103,10 +114,27 @@ impl GitStatusSegmentBuilder {
         }
     }
 
+    fn build_worktree_status(worktree: Option<&str>, icons: &GitStatusIcons) -> Option<String> {
+        worktree.map(|name| format!(" {}{name}", icons.worktree))
+    }
+
+    fn build_submodule_status(
+        superproject: Option<&str>,
+        icons: &GitStatusIcons,
+    ) -> Option<String> {
+        let superproject = Path::new(superproject?).file_name()?.to_string_lossy();
+        Some(format!(" {}{superproject}", icons.submodule))
+    }
+
     fn build_working_tree_status(
         working_tree: &WorkingTreeStatus,
         icons: &GitStatusIcons,
+        config: &WorkingTreeConfig,
     ) -> String {
+        if config.counts {
+            return Self::build_working_tree_counts(working_tree, config);
+        }
+
         let mut status = String::new();
         status.reserve(16);
 
@@ -133,16 +161,121 @@ impl GitStatusSegmentBuilder {
         }
     }
 
-    fn build_upstream_status(upstream: &UpstreamStatus, icons: &GitStatusIcons) -> Option<String> {
-        let behind_icon = &icons.behind;
-        let ahead_icon = &icons.ahead;
-        match (upstream.behind, upstream.ahead) {
+    fn build_working_tree_counts(
+        working_tree: &WorkingTreeStatus,
+        config: &WorkingTreeConfig,
+    ) -> String {
+        let build_group = |categories: &[(u32, &String)]| {
+            categories
+                .iter()
+                .filter(|(count, _)| *count > 0)
+                .map(|(count, template)| template.replace("{{.count}}", &count.to_string()))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let staged = build_group(&[
+            (working_tree.staged_new, &config.staged_new),
+            (working_tree.staged_changed, &config.staged_modified),
+            (working_tree.staged_deleted, &config.staged_deleted),
+            (working_tree.staged_renamed, &config.staged_renamed),
+        ]);
+        let unstaged = build_group(&[
+            (working_tree.unstaged_new, &config.unstaged_new),
+            (working_tree.unstaged_changed, &config.unstaged_modified),
+            (working_tree.unstaged_deleted, &config.unstaged_deleted),
+            (working_tree.unstaged_renamed, &config.unstaged_renamed),
+        ]);
+        let conflicted = build_group(&[(working_tree.conflicted, &config.conflicted)]);
+
+        let groups = [staged, unstaged, conflicted]
+            .into_iter()
+            .filter(|group| !group.is_empty())
+            .collect::<Vec<_>>();
+
+        if groups.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", groups.join(&config.separator))
+        }
+    }
+
+    fn build_upstream_status(
+        upstream: &UpstreamStatus,
+        icons: &GitStatusIcons,
+        has_remotes: bool,
+    ) -> Option<String> {
+        match upstream {
+            // Without any remote there is nowhere to push to, so a missing upstream is expected.
+            UpstreamStatus::Missing if has_remotes => Self::build_icon(&icons.no_upstream),
+            UpstreamStatus::Missing => None,
+            UpstreamStatus::Gone => Self::build_icon(&icons.gone_upstream),
+            UpstreamStatus::Tracking(status) => {
+                Self::build_ahead_behind(status, &icons.behind, &icons.ahead)
+            }
+        }
+    }
+
+    /// Empty icons, the default for states that aren't shown, are left out.
+    fn build_icon(icon: &str) -> Option<String> {
+        if icon.is_empty() {
+            None
+        } else {
+            Some(format!(" {icon}"))
+        }
+    }
+
+    fn build_ahead_behind(
+        status: &AheadBehind,
+        behind_icon: &str,
+        ahead_icon: &str,
+    ) -> Option<String> {
+        match (status.behind, status.ahead) {
             (0, 0) => None,
             (behind, 0) => Some(format!(" {behind_icon}{behind}")),
             (0, ahead) => Some(format!(" {ahead_icon}{ahead}")),
             (behind, ahead) => Some(format!(" {behind_icon}{behind}{ahead_icon}{ahead}")),
         }
     }
+
+    fn build_stash_status(stash: u32, icons: &GitStatusIcons) -> Option<String> {
+        if stash > 0 {
+            Some(format!(" {}{stash}", icons.stash))
+        } else {
+            None
+        }
+    }
+
+    fn build_lfs_status(lfs: &LfsStatus, icons: &GitStatusIcons) -> String {
+        if lfs.unsmudged {
+            format!(" {}", icons.lfs_unsmudged)
+        } else {
+            format!(" {}", icons.lfs)
+        }
+    }
+
+    fn build_sparse_status(sparse_checkout: bool, icons: &GitStatusIcons) -> Option<String> {
+        if sparse_checkout {
+            Some(format!(" {}", icons.sparse))
+        } else {
+            None
+        }
+    }
+
+    fn build_operation_status(operation: &OperationStatus, icons: &GitStatusIcons) -> String {
+        let icon = match operation.operation {
+            Operation::Merge => &icons.merge,
+            Operation::Revert => &icons.revert,
+            Operation::CherryPick => &icons.cherry_pick,
+            Operation::Bisect => &icons.bisect,
+            Operation::Rebase => &icons.rebase,
+            Operation::ApplyMailbox => &icons.apply_mailbox,
+        };
+        match &operation.progress {
+            Some(progress) => format!(" {icon} {}/{}", progress.current, progress.total),
+            None => format!(" {icon}"),
+        }
+    }
 }
 
 impl Default for GitStatusSegmentBuilder {
@@ -167,25 +300,60 @@ impl SegmentBuilder for GitStatusSegmentBuilder {
             config.commit_hash_length,
         );
 
-        let working_tree = Self::build_working_tree_status(&git_info.working_tree, &config.icons);
+        let worktree = Self::build_worktree_status(git_info.worktree.as_deref(), &config.icons);
 
-        let upstream = git_info
-            .upstream
+        let submodule =
+            Self::build_submodule_status(git_info.superproject.as_deref(), &config.icons);
+
+        let working_tree = Self::build_working_tree_status(
+            &git_info.working_tree,
+            &config.icons,
+            &config.working_tree,
+        );
+
+        let upstream = git_info.upstream.as_ref().and_then(|upstream| {
+            Self::build_upstream_status(upstream, &config.icons, !git_info.remotes.is_empty())
+        });
+
+        let base = git_info.base.as_ref().and_then(|base| {
+            Self::build_ahead_behind(base, &config.icons.base_behind, &config.icons.base_ahead)
+        });
+
+        let stash = Self::build_stash_status(git_info.stash, &config.icons);
+
+        let lfs = git_info
+            .lfs
             .as_ref()
-            .and_then(|upstream| Self::build_upstream_status(upstream, &config.icons));
+            .map(|lfs| Self::build_lfs_status(lfs, &config.icons));
+
+        let sparse = Self::build_sparse_status(git_info.sparse_checkout, &config.icons);
+
+        let operation = git_info
+            .operation
+            .as_ref()
+            .map(|operation| Self::build_operation_status(operation, &config.icons));
 
         let content = self.replacer.replace_all(
             &config.content,
             &[
                 remote,
                 head.as_ref(),
+                worktree.as_deref().unwrap_or_default(),
+                submodule.as_deref().unwrap_or_default(),
                 &working_tree,
                 upstream.as_deref().unwrap_or_default(),
+                base.as_deref().unwrap_or_default(),
+                stash.as_deref().unwrap_or_default(),
+                lfs.as_deref().unwrap_or_default(),
+                sparse.as_deref().unwrap_or_default(),
+                operation.as_deref().unwrap_or_default(),
             ],
         );
 
         let style = if git_info.working_tree.has_conflict() {
             &config.conflicted.style
+        } else if git_info.operation.is_some() {
+            &config.operation.style
         } else if git_info.working_tree.has_unstaged_changes() {
             &config.unstaged.style
         } else if git_info.working_tree.has_staged_changes() {
@@ -195,6 +363,76 @@ impl SegmentBuilder for GitStatusSegmentBuilder {
         };
         let style = style.to_ansi();
 
-        Some(Segment { content, style })
+        Some(Segment {
+            content,
+            style,
+            link: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_working_tree_counts() {
+        let config = WorkingTreeConfig::default();
+
+        let working_tree = WorkingTreeStatus::default();
+        assert_eq!(
+            GitStatusSegmentBuilder::build_working_tree_counts(&working_tree, &config),
+            ""
+        );
+
+        let working_tree = WorkingTreeStatus {
+            unstaged_changed: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            GitStatusSegmentBuilder::build_working_tree_counts(&working_tree, &config),
+            " ✚2"
+        );
+
+        let working_tree = WorkingTreeStatus {
+            staged_new: 3,
+            staged_changed: 2,
+            unstaged_new: 1,
+            unstaged_changed: 4,
+            conflicted: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            GitStatusSegmentBuilder::build_working_tree_counts(&working_tree, &config),
+            " +3 ~2 | ?1 ✚4 | !1"
+        );
+    }
+
+    #[test]
+    fn test_build_upstream_status() {
+        let mut icons = GitStatusIcons::default();
+        assert_eq!(
+            GitStatusSegmentBuilder::build_upstream_status(&UpstreamStatus::Missing, &icons, true),
+            None
+        );
+        assert_eq!(
+            GitStatusSegmentBuilder::build_upstream_status(&UpstreamStatus::Gone, &icons, true),
+            None
+        );
+
+        icons.no_upstream = "∅".to_string();
+        icons.gone_upstream = "✗".to_string();
+        assert_eq!(
+            GitStatusSegmentBuilder::build_upstream_status(&UpstreamStatus::Missing, &icons, true),
+            Some(" ∅".to_string())
+        );
+        assert_eq!(
+            GitStatusSegmentBuilder::build_upstream_status(&UpstreamStatus::Missing, &icons, false),
+            None
+        );
+        assert_eq!(
+            GitStatusSegmentBuilder::build_upstream_status(&UpstreamStatus::Gone, &icons, true),
+            Some(" ✗".to_string())
+        );
     }
 }
@@ -15,6 +15,10 @@ impl SegmentBuilder for GitUserSegmentBuilder {
         let content = config.content.replace("{{.name}}", user_name);
         let style = config.style.to_ansi();
 
-        Some(Segment { content, style })
+        Some(Segment {
+            content,
+            style,
+            link: None,
+        })
     }
 }
@@ -66,6 +66,26 @@ impl GlabMergeRequestSegmentBuilder {
         }
     }
 
+    fn build_unknown(&self, config: &GlabMergeRequestConfig) -> Segment {
+        let content = self.replacer.replace_all(
+            &config.content,
+            &[
+                config.icons.unknown_number.as_str(),
+                &format!(" {}", config.icons.unknown),
+                "",
+                "",
+                "",
+            ],
+        );
+        let style = config.draft.style.to_ansi();
+
+        Segment {
+            content,
+            style,
+            link: None,
+        }
+    }
+
     fn style<'a>(&self, config: &'a GlabMergeRequestConfig, mr: &MergeRequest) -> &'a Style {
         match (&mr.state, mr.is_draft) {
             (MergeRequestState::Open, false) => &config.open.style,
@@ -85,7 +105,10 @@ impl Default for GlabMergeRequestSegmentBuilder {
 impl SegmentBuilder for GlabMergeRequestSegmentBuilder {
     fn build(&self, ctx: &Context) -> Option<Segment> {
         let config = &ctx.config.glab_merge_request;
-        let mr = &ctx.glab_info?.merge_request;
+        let glab_info = ctx.glab_info?;
+        let Some(mr) = glab_info.merge_request.as_ref() else {
+            return glab_info.timed_out.then(|| self.build_unknown(config));
+        };
 
         let number = self.build_number(config, mr);
         let state = self.build_state(config, mr);
@@ -105,7 +128,12 @@ impl SegmentBuilder for GlabMergeRequestSegmentBuilder {
         );
 
         let style = self.style(config, mr).to_ansi();
+        let link = Some(mr.url.clone());
 
-        Some(Segment { content, style })
+        Some(Segment {
+            content,
+            style,
+            link,
+        })
     }
 }
@@ -0,0 +1,144 @@
+use super::{Context, Segment, SegmentBuilder};
+use crate::{config::hg_status::HgStatusIcons, info::hg::HgInfo};
+use aho_corasick::AhoCorasick;
+
+#[derive(Debug)]
+pub struct HgStatusSegmentBuilder {
+    replacer: AhoCorasick,
+}
+
+impl HgStatusSegmentBuilder {
+    fn build_working_tree_status(hg_info: &HgInfo, icons: &HgStatusIcons) -> String {
+        // The status is unknown when hg did not finish in time, which must not look clean.
+        if hg_info.timed_out {
+            return format!(" {}", icons.unknown);
+        }
+
+        let working_tree = &hg_info.working_tree;
+        let mut status = String::new();
+        if working_tree.untracked {
+            status += &icons.untracked;
+        }
+        if working_tree.modified {
+            status += &icons.modified;
+        }
+
+        if !status.is_empty() {
+            format!(" {status}")
+        } else {
+            status
+        }
+    }
+}
+
+impl Default for HgStatusSegmentBuilder {
+    fn default() -> Self {
+        let replacer =
+            AhoCorasick::new(["{{.branch}}", "{{.bookmark}}", "{{.working_tree}}"]).unwrap();
+        Self { replacer }
+    }
+}
+
+impl SegmentBuilder for HgStatusSegmentBuilder {
+    fn build(&self, ctx: &Context) -> Option<Segment> {
+        let config = &ctx.config.hg_status;
+        let hg_info = ctx.hg_info?;
+        let icons = &config.icons;
+
+        let branch = if icons.branch.is_empty() {
+            hg_info.branch.clone()
+        } else {
+            format!("{} {}", icons.branch, hg_info.branch)
+        };
+        let bookmark = hg_info
+            .bookmark
+            .as_ref()
+            .map(|bookmark| format!(" {}{bookmark}", icons.bookmark))
+            .unwrap_or_default();
+        let working_tree = Self::build_working_tree_status(hg_info, icons);
+
+        let content = self
+            .replacer
+            .replace_all(&config.content, &[branch, bookmark, working_tree]);
+
+        let style = if hg_info.timed_out || hg_info.working_tree.is_dirty() {
+            &config.dirty.style
+        } else {
+            &config.clean.style
+        };
+
+        Some(Segment {
+            content,
+            style: style.to_ansi(),
+            link: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        command::SegmentArgs, config::Config, info::hg::HgWorkingTreeStatus, shell::Shell,
+    };
+
+    #[test]
+    fn test_build() {
+        let config = &Config::default();
+        let args = &SegmentArgs {
+            right: false,
+            transient: false,
+            continuation: false,
+            exit_status: 0,
+            duration: 0.0,
+            jobs: 0,
+            width: 100,
+            encoded_git_info: None,
+            encoded_gh_info: None,
+            encoded_glab_info: None,
+            encoded_jj_info: None,
+            encoded_hg_info: None,
+            shell: Shell::Zsh,
+        };
+        let target = HgStatusSegmentBuilder::default();
+
+        let ctx = Context::new(config, args, None, None, None, None, None);
+        assert_eq!(target.build(&ctx), None);
+
+        let hg_info = HgInfo {
+            branch: "default".to_string(),
+            bookmark: None,
+            working_tree: HgWorkingTreeStatus::default(),
+            timed_out: false,
+        };
+        let ctx = Context::new(config, args, None, None, None, None, Some(&hg_info));
+        let actual = target.build(&ctx).unwrap();
+        assert_eq!(actual.content, " ☿ default ");
+        assert_eq!(actual.style, config.hg_status.clean.style.to_ansi());
+
+        let hg_info = HgInfo {
+            branch: "stable".to_string(),
+            bookmark: Some("feature".to_string()),
+            working_tree: HgWorkingTreeStatus {
+                modified: true,
+                untracked: true,
+            },
+            timed_out: false,
+        };
+        let ctx = Context::new(config, args, None, None, None, None, Some(&hg_info));
+        let actual = target.build(&ctx).unwrap();
+        assert_eq!(actual.content, " ☿ stable ⚑feature +… ");
+        assert_eq!(actual.style, config.hg_status.dirty.style.to_ansi());
+
+        let hg_info = HgInfo {
+            branch: "default".to_string(),
+            bookmark: None,
+            working_tree: HgWorkingTreeStatus::default(),
+            timed_out: true,
+        };
+        let ctx = Context::new(config, args, None, None, None, None, Some(&hg_info));
+        let actual = target.build(&ctx).unwrap();
+        assert_eq!(actual.content, " ☿ default ? ");
+        assert_eq!(actual.style, config.hg_status.dirty.style.to_ansi());
+    }
+}
@@ -0,0 +1,101 @@
+use super::{Context, Segment, SegmentBuilder};
+use aho_corasick::AhoCorasick;
+
+#[derive(Debug)]
+pub struct JjStatusSegmentBuilder {
+    replacer: AhoCorasick,
+}
+
+impl Default for JjStatusSegmentBuilder {
+    fn default() -> Self {
+        let replacer =
+            AhoCorasick::new(["{{.change_id}}", "{{.bookmarks}}", "{{.conflict}}"]).unwrap();
+        Self { replacer }
+    }
+}
+
+impl SegmentBuilder for JjStatusSegmentBuilder {
+    fn build(&self, ctx: &Context) -> Option<Segment> {
+        let config = &ctx.config.jj_status;
+        let jj_info = ctx.jj_info?;
+        let icons = &config.icons;
+
+        let change_id = format!("{}{}", icons.change, jj_info.change_id);
+        let bookmarks: String = jj_info
+            .bookmarks
+            .iter()
+            .map(|bookmark| format!(" {}{bookmark}", icons.bookmark))
+            .collect();
+        let conflict = if jj_info.conflict {
+            format!(" {}", icons.conflict)
+        } else {
+            String::new()
+        };
+
+        let content = self
+            .replacer
+            .replace_all(&config.content, &[change_id, bookmarks, conflict]);
+
+        let style = if jj_info.conflict {
+            &config.conflicted.style
+        } else {
+            &config.normal.style
+        };
+
+        Some(Segment {
+            content,
+            style: style.to_ansi(),
+            link: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{command::SegmentArgs, config::Config, info::jj::JjInfo, shell::Shell};
+
+    #[test]
+    fn test_build() {
+        let config = &Config::default();
+        let args = &SegmentArgs {
+            right: false,
+            transient: false,
+            continuation: false,
+            exit_status: 0,
+            duration: 0.0,
+            jobs: 0,
+            width: 100,
+            encoded_git_info: None,
+            encoded_gh_info: None,
+            encoded_glab_info: None,
+            encoded_jj_info: None,
+            encoded_hg_info: None,
+            shell: Shell::Zsh,
+        };
+        let target = JjStatusSegmentBuilder::default();
+
+        let ctx = Context::new(config, args, None, None, None, None, None);
+        assert_eq!(target.build(&ctx), None);
+
+        let jj_info = JjInfo {
+            change_id: "kmpvrszu".to_string(),
+            bookmarks: vec!["main".to_string(), "feature".to_string()],
+            conflict: false,
+        };
+        let ctx = Context::new(config, args, None, None, None, Some(&jj_info), None);
+        let actual = target.build(&ctx).unwrap();
+        assert_eq!(actual.content, " @kmpvrszu ⚑main ⚑feature ");
+        assert_eq!(actual.style, config.jj_status.normal.style.to_ansi());
+
+        let jj_info = JjInfo {
+            change_id: "kmp".to_string(),
+            bookmarks: vec![],
+            conflict: true,
+        };
+        let ctx = Context::new(config, args, None, None, None, Some(&jj_info), None);
+        let actual = target.build(&ctx).unwrap();
+        assert_eq!(actual.content, " @kmp ✘ ");
+        assert_eq!(actual.style, config.jj_status.conflicted.style.to_ansi());
+    }
+}
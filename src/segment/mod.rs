@@ -1,28 +1,36 @@
+mod continuation;
 mod direnv;
 mod duration;
 mod gh_actions;
 mod gh_pull_request;
+mod git_commit;
+mod git_diff;
 mod git_status;
 mod git_user;
 mod glab_merge_request;
+mod hg_status;
+mod jj_status;
 mod os;
 mod path;
 mod presenter;
 mod status;
 mod time;
+mod title;
 mod user;
 
 use self::{
-    duration::DurationSegmentBuilder, gh_pull_request::GhPullRequestSegmentBuilder,
-    git_status::GitStatusSegmentBuilder, git_user::GitUserSegmentBuilder,
-    glab_merge_request::GlabMergeRequestSegmentBuilder, os::OsSegmentBuilder,
+    continuation::ContinuationSegmentBuilder, duration::DurationSegmentBuilder,
+    gh_pull_request::GhPullRequestSegmentBuilder, git_commit::GitCommitSegmentBuilder,
+    git_diff::GitDiffSegmentBuilder, git_status::GitStatusSegmentBuilder,
+    git_user::GitUserSegmentBuilder, glab_merge_request::GlabMergeRequestSegmentBuilder,
+    hg_status::HgStatusSegmentBuilder, jj_status::JjStatusSegmentBuilder, os::OsSegmentBuilder,
     path::PathSegmentBuilder, presenter::Presenter, status::StatusSegmentBuilder,
-    time::TimeSegmentBuilder, user::UserSegmentBuilder,
+    time::TimeSegmentBuilder, title::TitleBuilder, user::UserSegmentBuilder,
 };
 use crate::{
     command::SegmentArgs,
     config::{Config, SegmentKind},
-    info::{gh::GhInfo, git::GitInfo, glab::GlabInfo},
+    info::{gh::GhInfo, git::GitInfo, glab::GlabInfo, hg::HgInfo, jj::JjInfo},
     segment::gh_actions::GhActionsSegmentBuilder,
 };
 use direnv::DirenvSegmentBuilder;
@@ -33,6 +41,7 @@ use std::io;
 pub struct Segment {
     pub content: String,
     pub style: Style,
+    pub link: Option<String>,
 }
 
 #[derive(Debug)]
@@ -42,6 +51,8 @@ pub struct Context<'a> {
     git_info: Option<&'a GitInfo>,
     gh_info: Option<&'a GhInfo>,
     glab_info: Option<&'a GlabInfo>,
+    jj_info: Option<&'a JjInfo>,
+    hg_info: Option<&'a HgInfo>,
 }
 
 impl<'a> Context<'a> {
@@ -51,6 +62,8 @@ impl<'a> Context<'a> {
         git_info: Option<&'a GitInfo>,
         gh_info: Option<&'a GhInfo>,
         glab_info: Option<&'a GlabInfo>,
+        jj_info: Option<&'a JjInfo>,
+        hg_info: Option<&'a HgInfo>,
     ) -> Self {
         Self {
             config,
@@ -58,6 +71,8 @@ impl<'a> Context<'a> {
             git_info,
             gh_info,
             glab_info,
+            jj_info,
+            hg_info,
         }
     }
 }
@@ -75,11 +90,16 @@ struct SegmentBuilders<'a> {
     time: TimeSegmentBuilder<'a>,
     user: UserSegmentBuilder<'a>,
     git_status: GitStatusSegmentBuilder,
+    git_diff: GitDiffSegmentBuilder,
+    git_commit: GitCommitSegmentBuilder<'a>,
     git_user: GitUserSegmentBuilder,
     gh_pull_request: GhPullRequestSegmentBuilder,
     gh_actions: GhActionsSegmentBuilder,
     glab_merge_request: GlabMergeRequestSegmentBuilder,
+    jj_status: JjStatusSegmentBuilder,
+    hg_status: HgStatusSegmentBuilder,
     direnv: DirenvSegmentBuilder,
+    continuation: ContinuationSegmentBuilder,
 }
 
 impl SegmentBuilders<'_> {
@@ -92,11 +112,16 @@ impl SegmentBuilders<'_> {
             SegmentKind::Time => self.time.build(ctx),
             SegmentKind::User => self.user.build(ctx),
             SegmentKind::GitStatus => self.git_status.build(ctx),
+            SegmentKind::GitDiff => self.git_diff.build(ctx),
+            SegmentKind::GitCommit => self.git_commit.build(ctx),
             SegmentKind::GitUser => self.git_user.build(ctx),
             SegmentKind::GhPullRequest => self.gh_pull_request.build(ctx),
             SegmentKind::GhActions => self.gh_actions.build(ctx),
             SegmentKind::GlabMergeRequest => self.glab_merge_request.build(ctx),
+            SegmentKind::JjStatus => self.jj_status.build(ctx),
+            SegmentKind::HgStatus => self.hg_status.build(ctx),
             SegmentKind::Direnv => self.direnv.build(ctx),
+            SegmentKind::Continuation => self.continuation.build(ctx),
         }
     }
 
@@ -114,8 +139,25 @@ pub fn print_segments(ctx: &Context) -> io::Result<()> {
     let mut stdout = std::io::stdout().lock();
     let presenter = Presenter::new(ctx.config, &ctx.args.shell, ctx.args.width);
 
-    let segments = &ctx.config.segments;
+    let segments = if ctx.args.transient {
+        &ctx.config.transient.segments
+    } else if ctx.args.continuation {
+        &ctx.config.continuation.segments
+    } else {
+        &ctx.config.segments
+    };
     if !ctx.args.right {
+        if !ctx.args.transient && !ctx.args.continuation {
+            let title = TitleBuilder::default();
+            presenter.display_title(
+                &mut stdout,
+                title.build_title(ctx).as_deref(),
+                title.build_cwd_url(ctx).as_deref(),
+            )?;
+        }
+
+        presenter.start_prompt(&mut stdout, ctx.args.continuation)?;
+
         for (row, line) in segments.iter().enumerate() {
             if row > 0 {
                 presenter.next_line(&mut stdout)?;
@@ -131,6 +173,7 @@ pub fn print_segments(ctx: &Context) -> io::Result<()> {
         }
 
         presenter.finish_left(&mut stdout)?;
+        presenter.end_prompt(&mut stdout)?;
     } else if let Some(last_line) = segments.last() {
         // right prompt
         let right = builders.build_segments(ctx, &last_line.right);
@@ -10,6 +10,7 @@ impl OsSegmentBuilder {
         Some(Segment {
             content: content.to_string(),
             style: style.to_ansi(),
+            link: None,
         })
     }
 }
@@ -1,22 +1,38 @@
 mod shrink;
 
 use self::shrink::shrink_path;
-use super::{Context, Segment, SegmentBuilder};
+use super::{Context, Segment, SegmentBuilder, user::hostname};
 use std::path::{Path, PathBuf};
 
-fn current_dir() -> Option<PathBuf> {
+pub(super) fn current_dir() -> Option<PathBuf> {
     std::env::var_os("PWD")
         .map(PathBuf::from)
         .or_else(|| std::env::current_dir().ok())
 }
 
-fn home_dir() -> Option<PathBuf> {
+pub(super) fn home_dir() -> Option<PathBuf> {
     dirs::home_dir()
 }
 
+/// Returns the `file://` URL of the path, as used by OSC 7 and OSC 8.
+pub(super) fn file_url(path: &Path, host: &str) -> String {
+    let encoded_path: String = path
+        .to_string_lossy()
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect();
+    format!("file://{host}{encoded_path}")
+}
+
 pub struct PathSegmentBuilder<'a> {
     current_dir: &'a dyn Fn() -> Option<PathBuf>,
     home_dir: &'a dyn Fn() -> Option<PathBuf>,
+    hostname: &'a dyn Fn() -> Option<String>,
 }
 
 impl Default for PathSegmentBuilder<'_> {
@@ -24,6 +40,7 @@ impl Default for PathSegmentBuilder<'_> {
         Self {
             current_dir: &current_dir,
             home_dir: &home_dir,
+            hostname: &hostname,
         }
     }
 }
@@ -36,14 +53,27 @@ impl SegmentBuilder for PathSegmentBuilder<'_> {
         let is_dir = cwd.as_ref().map(|cwd| cwd.is_dir()).unwrap_or(false);
 
         let home = (self.home_dir)();
-        let project_root = ctx.git_info.and_then(|info| info.workdir.as_deref());
+        let project_roots: Vec<&Path> = ctx
+            .git_info
+            .map(|info| {
+                [&info.workdir, &info.superproject]
+                    .into_iter()
+                    .flatten()
+                    .map(Path::new)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let link = cwd
+            .as_ref()
+            .map(|cwd| file_url(cwd, &(self.hostname)().unwrap_or_default()));
 
         let shrinked_path = cwd
             .map(|cwd| {
                 shrink_path(
                     &cwd,
                     home.as_deref(),
-                    project_root.map(Path::new),
+                    &project_roots,
                     &config.aliases,
                     config.shrink.enabled,
                     config.shrink.length,
@@ -62,6 +92,7 @@ impl SegmentBuilder for PathSegmentBuilder<'_> {
         Some(Segment {
             content,
             style: style.to_ansi(),
+            link,
         })
     }
 }
@@ -77,6 +108,8 @@ mod tests {
         let config = &Config::default();
         let args = &SegmentArgs {
             right: false,
+            transient: false,
+            continuation: false,
             exit_status: 0,
             duration: 0.0,
             jobs: 0,
@@ -84,15 +117,18 @@ mod tests {
             encoded_git_info: None,
             encoded_gh_info: None,
             encoded_glab_info: None,
+            encoded_jj_info: None,
+            encoded_hg_info: None,
             shell: Shell::Zsh,
         };
-        let ctx = Context::new(config, args, None, None, None);
+        let ctx = Context::new(config, args, None, None, None, None, None);
 
         struct Scenario<'a> {
             testname: &'a str,
             cwd: Option<&'a str>,
             home: Option<&'a str>,
             expected_content: &'a str,
+            expected_link: Option<&'a str>,
         }
 
         let scenarios = &[
@@ -101,12 +137,16 @@ mod tests {
                 cwd: Some("/home/ayaka/repos/github.com/Ryooooooga/croque/src"),
                 home: Some("/home/ayaka"),
                 expected_content: " ~/r/g/R/c/src ",
+                expected_link: Some(
+                    "file://host/home/ayaka/repos/github.com/Ryooooooga/croque/src",
+                ),
             },
             Scenario {
                 testname: "should return <unknown> if cwd is none",
                 cwd: None,
                 home: Some("/home/ayaka"),
                 expected_content: " <unknown> ",
+                expected_link: None,
             },
         ];
 
@@ -114,11 +154,13 @@ mod tests {
             let target = PathSegmentBuilder {
                 current_dir: &|| s.cwd.map(PathBuf::from),
                 home_dir: &|| s.home.map(PathBuf::from),
+                hostname: &|| Some("host".to_string()),
             };
 
             let actual = target.build(&ctx).unwrap();
 
             assert_eq!(&actual.content, s.expected_content, "{}", s.testname);
+            assert_eq!(actual.link.as_deref(), s.expected_link, "{}", s.testname);
         }
     }
 }
@@ -95,7 +95,7 @@ const PATH_SEPARATOR: &str = "/";
 pub fn shrink_path(
     path: &Path,
     home: Option<&Path>,
-    project_root: Option<&Path>,
+    project_roots: &[&Path],
     aliases: &[PathAlias],
     shrink_enabled: bool,
     shrink_len: usize,
@@ -134,7 +134,7 @@ pub fn shrink_path(
         };
 
         let is_first = reversed_path_segments.is_empty();
-        let is_project_root = Some(path) == project_root;
+        let is_project_root = project_roots.contains(&path);
         let should_shrink = shrink_enabled && !is_first && !is_project_root;
 
         let basename = path.file_name().unwrap_or_default().to_string_lossy();
@@ -177,7 +177,7 @@ fn test_shrink_path() {
         &shrink_path(
             &PathBuf::from("/home/ayaka/repos/repo_a"),
             home,
-            None,
+            &[],
             aliases,
             true,
             1
@@ -189,7 +189,7 @@ fn test_shrink_path() {
         &shrink_path(
             &PathBuf::from("/home/ayaka/repos/repo_a"),
             home,
-            None,
+            &[],
             aliases,
             false,
             1
@@ -201,7 +201,7 @@ fn test_shrink_path() {
         &shrink_path(
             &PathBuf::from("/home/ayaka/repos/repo_a/src"),
             home,
-            Some(&PathBuf::from("/home/ayaka/repos/repo_a")),
+            &[&PathBuf::from("/home/ayaka/repos/repo_a")],
             aliases,
             true,
             1
@@ -209,11 +209,26 @@ fn test_shrink_path() {
         "~/r/repo_a/src"
     );
 
+    assert_eq!(
+        &shrink_path(
+            &PathBuf::from("/home/ayaka/repos/repo_a/libs/lib_b/src"),
+            home,
+            &[
+                &PathBuf::from("/home/ayaka/repos/repo_a/libs/lib_b"),
+                &PathBuf::from("/home/ayaka/repos/repo_a"),
+            ],
+            aliases,
+            true,
+            1
+        ),
+        "~/r/repo_a/l/lib_b/src"
+    );
+
     assert_eq!(
         &shrink_path(
             &PathBuf::from("/home/ayaka/.config/croque/"),
             home,
-            None,
+            &[],
             aliases,
             true,
             2
@@ -225,7 +240,7 @@ fn test_shrink_path() {
         &shrink_path(
             &PathBuf::from("/home/ayaka/今日の献立/2023 02 14"),
             home,
-            None,
+            &[],
             aliases,
             true,
             2
@@ -237,7 +252,7 @@ fn test_shrink_path() {
         &shrink_path(
             &PathBuf::from("/home/ayaka/.Trash/a"),
             home,
-            None,
+            &[],
             aliases,
             true,
             2
@@ -246,22 +261,22 @@ fn test_shrink_path() {
     );
 
     assert_eq!(
-        &shrink_path(&PathBuf::from("/home"), home, None, aliases, true, 1),
+        &shrink_path(&PathBuf::from("/home"), home, &[], aliases, true, 1),
         "/home"
     );
 
     assert_eq!(
-        &shrink_path(&PathBuf::from("/home/"), home, None, aliases, true, 1),
+        &shrink_path(&PathBuf::from("/home/"), home, &[], aliases, true, 1),
         "/home"
     );
 
     assert_eq!(
-        &shrink_path(&PathBuf::from("/home/nyan"), home, None, aliases, true, 1),
+        &shrink_path(&PathBuf::from("/home/nyan"), home, &[], aliases, true, 1),
         "/h/nyan"
     );
 
     assert_eq!(
-        &shrink_path(&PathBuf::from("/"), home, None, aliases, true, 1),
+        &shrink_path(&PathBuf::from("/"), home, &[], aliases, true, 1),
         "/"
     );
 }
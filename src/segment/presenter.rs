@@ -33,7 +33,22 @@ impl<'a> Presenter<'a> {
         let content = &segment.content;
         let escaped_content = self.shell.escape(content);
 
-        write!(out, "{prefix}{escaped_content}{suffix}").unwrap();
+        if let Some(link) = segment
+            .link
+            .as_ref()
+            .filter(|_| self.config.hyperlinks.enabled)
+        {
+            let escaped_link = self.shell.escape(link);
+            let link_start = self.shell.osc(Hyperlink(&escaped_link));
+            let link_end = self.shell.osc(Hyperlink(""));
+            write!(
+                out,
+                "{link_start}{prefix}{escaped_content}{suffix}{link_end}"
+            )
+            .unwrap();
+        } else {
+            write!(out, "{prefix}{escaped_content}{suffix}").unwrap();
+        }
         content.width()
     }
 
@@ -178,6 +193,42 @@ impl<'a> Presenter<'a> {
         Ok(())
     }
 
+    pub fn display_title<W: io::Write>(
+        &self,
+        mut out: W,
+        title: Option<&str>,
+        cwd_url: Option<&str>,
+    ) -> io::Result<()> {
+        if let Some(title) = title {
+            let escaped_title = self.shell.escape(title);
+            write!(out, "{}", self.shell.osc(SetTitle(&escaped_title)))?;
+        }
+        if let Some(cwd_url) = cwd_url {
+            let escaped_cwd_url = self.shell.escape(cwd_url);
+            write!(out, "{}", self.shell.osc(ReportCwd(&escaped_cwd_url)))?;
+        }
+        Ok(())
+    }
+
+    pub fn start_prompt<W: io::Write>(&self, mut out: W, secondary: bool) -> io::Result<()> {
+        if self.config.semantic_prompt.enabled {
+            let mark = if secondary {
+                SemanticPromptMark::SecondaryPrompt
+            } else {
+                SemanticPromptMark::Prompt
+            };
+            write!(out, "{}", self.shell.osc(mark))?;
+        }
+        Ok(())
+    }
+
+    pub fn end_prompt<W: io::Write>(&self, mut out: W) -> io::Result<()> {
+        if self.config.semantic_prompt.enabled {
+            write!(out, "{}", self.shell.osc(SemanticPromptMark::Input))?;
+        }
+        Ok(())
+    }
+
     pub fn display_right<W: io::Write>(&self, mut out: W, right: &[Segment]) -> io::Result<()> {
         let (right_contents, _) = self.right_contents(right);
 
@@ -194,3 +245,48 @@ impl fmt::Display for MoveTo {
         write!(f, "\x1b[{}G", self.0 + 1)
     }
 }
+
+#[derive(Debug)]
+struct Hyperlink<'a>(&'a str);
+
+impl fmt::Display for Hyperlink<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "8;;{}", self.0)
+    }
+}
+
+#[derive(Debug)]
+struct SetTitle<'a>(&'a str);
+
+impl fmt::Display for SetTitle<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0;{}", self.0)
+    }
+}
+
+#[derive(Debug)]
+struct ReportCwd<'a>(&'a str);
+
+impl fmt::Display for ReportCwd<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "7;{}", self.0)
+    }
+}
+
+/// OSC 133 marks, which let terminals tell prompts apart from command output.
+#[derive(Debug)]
+enum SemanticPromptMark {
+    Prompt,
+    SecondaryPrompt,
+    Input,
+}
+
+impl fmt::Display for SemanticPromptMark {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SemanticPromptMark::Prompt => write!(f, "133;A"),
+            SemanticPromptMark::SecondaryPrompt => write!(f, "133;A;k=s"),
+            SemanticPromptMark::Input => write!(f, "133;B"),
+        }
+    }
+}
@@ -69,6 +69,7 @@ impl SegmentBuilder for StatusSegmentBuilder<'_> {
         Some(Segment {
             content,
             style: style.to_ansi(),
+            link: None,
         })
     }
 }
@@ -100,6 +101,7 @@ mod tests {
                 expected: Some(Segment {
                     content: " ✓ ".to_string(),
                     style: config.status.success.style.to_ansi(),
+                    link: None,
                 }),
             },
             Scenario {
@@ -110,6 +112,7 @@ mod tests {
                 expected: Some(Segment {
                     content: "  1 ".to_string(),
                     style: config.status.error.style.to_ansi(),
+                    link: None,
                 }),
             },
             Scenario {
@@ -120,6 +123,7 @@ mod tests {
                 expected: Some(Segment {
                     content: "  ".to_string(),
                     style: config.status.error.style.to_ansi(),
+                    link: None,
                 }),
             },
             Scenario {
@@ -130,6 +134,7 @@ mod tests {
                 expected: Some(Segment {
                     content: "  130   ".to_string(),
                     style: config.status.error.style.to_ansi(),
+                    link: None,
                 }),
             },
         ];
@@ -138,6 +143,8 @@ mod tests {
             config.status.error.display_exit_code = s.display_exit_code;
             let args = &SegmentArgs {
                 right: false,
+                transient: false,
+                continuation: false,
                 exit_status: s.exit_status,
                 duration: 0.0,
                 jobs: s.jobs,
@@ -145,9 +152,11 @@ mod tests {
                 encoded_git_info: None,
                 encoded_gh_info: None,
                 encoded_glab_info: None,
+                encoded_jj_info: None,
+                encoded_hg_info: None,
                 shell: Shell::Zsh,
             };
-            let ctx = Context::new(&config, args, None, None, None);
+            let ctx = Context::new(&config, args, None, None, None, None, None);
 
             let is_root = || s.is_root;
             let target = StatusSegmentBuilder::new(&is_root);
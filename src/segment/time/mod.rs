@@ -29,6 +29,10 @@ impl SegmentBuilder for TimeSegmentBuilder<'_> {
         let content = config.content.replace("{{.time}}", &time);
         let style = config.style.to_ansi();
 
-        Some(Segment { content, style })
+        Some(Segment {
+            content,
+            style,
+            link: None,
+        })
     }
 }
@@ -0,0 +1,160 @@
+use super::{
+    Context,
+    path::{current_dir, file_url, home_dir},
+    user::{hostname, username},
+};
+use crate::info::git::Head;
+use aho_corasick::AhoCorasick;
+use std::path::{Path, PathBuf};
+
+const SHORT_HASH_LENGTH: usize = 7;
+
+pub struct TitleBuilder<'a> {
+    replacer: AhoCorasick,
+    current_dir: &'a dyn Fn() -> Option<PathBuf>,
+    home_dir: &'a dyn Fn() -> Option<PathBuf>,
+    username: &'a dyn Fn() -> Option<String>,
+    hostname: &'a dyn Fn() -> Option<String>,
+}
+
+impl Default for TitleBuilder<'_> {
+    fn default() -> Self {
+        let replacer =
+            AhoCorasick::new(["{{.path}}", "{{.user}}", "{{.host}}", "{{.git_head}}"]).unwrap();
+        Self {
+            replacer,
+            current_dir: &current_dir,
+            home_dir: &home_dir,
+            username: &username,
+            hostname: &hostname,
+        }
+    }
+}
+
+impl TitleBuilder<'_> {
+    pub fn build_title(&self, ctx: &Context) -> Option<String> {
+        let config = &ctx.config.title;
+        if !config.enabled {
+            return None;
+        }
+
+        let path = (self.current_dir)()
+            .map(|cwd| Self::abbreviate_home(&cwd, (self.home_dir)().as_deref()))
+            .unwrap_or_else(|| String::from("<unknown>"));
+        let user = (self.username)().unwrap_or_else(|| String::from("?"));
+        let host = (self.hostname)().unwrap_or_else(|| String::from("?"));
+        let git_head = match ctx.git_info.map(|info| &info.head) {
+            Some(Head::Branch(branch)) => branch.as_str(),
+            Some(Head::Tag(tag)) => tag.as_str(),
+            Some(Head::Commit(hash)) => &hash[..hash.len().min(SHORT_HASH_LENGTH)],
+            None => "",
+        };
+
+        let title = self
+            .replacer
+            .replace_all(&config.content, &[&path, &user, &host, git_head]);
+
+        // Control characters would terminate the escape sequence early.
+        Some(title.trim().chars().filter(|c| !c.is_control()).collect())
+    }
+
+    /// Returns the `file://` URL of the current directory for OSC 7.
+    pub fn build_cwd_url(&self, ctx: &Context) -> Option<String> {
+        if !ctx.config.title.report_cwd {
+            return None;
+        }
+
+        let cwd = (self.current_dir)()?;
+        let host = (self.hostname)().unwrap_or_default();
+        Some(file_url(&cwd, &host))
+    }
+
+    fn abbreviate_home(cwd: &Path, home: Option<&Path>) -> String {
+        match home.and_then(|home| cwd.strip_prefix(home).ok()) {
+            Some(rest) if rest.as_os_str().is_empty() => String::from("~"),
+            Some(rest) => format!("~/{}", rest.to_string_lossy()),
+            None => cwd.to_string_lossy().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        command::SegmentArgs,
+        config::Config,
+        info::git::{GitInfo, WorkingTreeStatus},
+        shell::Shell,
+    };
+
+    #[test]
+    fn test_build_title() {
+        let mut config = Config::default();
+        config.title.enabled = true;
+        config.title.report_cwd = true;
+
+        let args = &SegmentArgs {
+            right: false,
+            transient: false,
+            continuation: false,
+            exit_status: 0,
+            duration: 0.0,
+            jobs: 0,
+            width: 100,
+            encoded_git_info: None,
+            encoded_gh_info: None,
+            encoded_glab_info: None,
+            encoded_jj_info: None,
+            encoded_hg_info: None,
+            shell: Shell::Zsh,
+        };
+        let git_info = GitInfo {
+            workdir: Some("/home/ayaka/my repo".to_string()),
+            worktree: None,
+            superproject: None,
+            head: Head::Commit("0123456789abcdef".to_string()),
+            working_tree: WorkingTreeStatus::default(),
+            upstream: None,
+            base: None,
+            remotes: vec![],
+            user: None,
+            operation: None,
+            stash: 0,
+            diff: None,
+            commit: None,
+            lfs: None,
+            sparse_checkout: false,
+        };
+
+        let target = TitleBuilder {
+            current_dir: &|| Some(PathBuf::from("/home/ayaka/my repo")),
+            home_dir: &|| Some(PathBuf::from("/home/ayaka")),
+            username: &|| Some("ayaka".to_string()),
+            hostname: &|| Some("host".to_string()),
+            ..Default::default()
+        };
+
+        let ctx = Context::new(&config, args, None, None, None, None, None);
+        assert_eq!(
+            target.build_title(&ctx).as_deref(),
+            Some("ayaka@host: ~/my repo")
+        );
+        assert_eq!(
+            target.build_cwd_url(&ctx).as_deref(),
+            Some("file://host/home/ayaka/my%20repo")
+        );
+
+        let ctx = Context::new(&config, args, Some(&git_info), None, None, None, None);
+        assert_eq!(
+            target.build_title(&ctx).as_deref(),
+            Some("ayaka@host: ~/my repo 0123456")
+        );
+
+        config.title.enabled = false;
+        config.title.report_cwd = false;
+        let ctx = Context::new(&config, args, Some(&git_info), None, None, None, None);
+        assert_eq!(target.build_title(&ctx), None);
+        assert_eq!(target.build_cwd_url(&ctx), None);
+    }
+}
@@ -3,16 +3,16 @@ use aho_corasick::AhoCorasick;
 use super::{Context, Segment, SegmentBuilder};
 
 #[cfg(not(target_os = "windows"))]
-fn username() -> Option<String> {
+pub(super) fn username() -> Option<String> {
     uzers::get_current_username().map(|username| username.to_string_lossy().to_string())
 }
 
 #[cfg(target_os = "windows")]
-fn username() -> Option<String> {
+pub(super) fn username() -> Option<String> {
     std::env::var_os("USERNAME").map(|username| username.to_string_lossy().to_string())
 }
 
-fn hostname() -> Option<String> {
+pub(super) fn hostname() -> Option<String> {
     hostname::get()
         .ok()
         .map(|hostname| hostname.to_string_lossy().to_string())
@@ -55,6 +55,7 @@ impl SegmentBuilder for UserSegmentBuilder<'_> {
         Some(Segment {
             content,
             style: config.style.to_ansi(),
+            link: None,
         })
     }
 }
@@ -105,6 +106,8 @@ mod tests {
 
             let args = &SegmentArgs {
                 right: false,
+                transient: false,
+                continuation: false,
                 exit_status: 0,
                 duration: 0.0,
                 jobs: 0,
@@ -112,10 +115,12 @@ mod tests {
                 encoded_git_info: None,
                 encoded_gh_info: None,
                 encoded_glab_info: None,
+                encoded_jj_info: None,
+                encoded_hg_info: None,
                 shell: Shell::Zsh,
             };
 
-            let ctx = Context::new(&config, args, None, None, None);
+            let ctx = Context::new(&config, args, None, None, None, None, None);
 
             let username = || s.username.map(String::from);
             let hostname = || s.hostname.map(String::from);
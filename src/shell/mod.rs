@@ -4,7 +4,12 @@ use std::borrow::Cow;
 #[derive(Debug, Clone, clap::ValueEnum)]
 pub enum Shell {
     Bash,
+    Elvish,
     Fish,
+    Nu,
+    #[value(name = "powershell", alias = "pwsh")]
+    Pwsh,
+    Xonsh,
     Zsh,
 }
 
@@ -13,18 +18,43 @@ impl Shell {
         PromptControl::new(self, c)
     }
 
+    pub fn osc<C: fmt::Display>(&self, c: C) -> PromptControl<'_, Osc<C>> {
+        self.control(Osc::new(c, self.string_terminator()))
+    }
+
     fn controls(&self) -> (&'static str, &'static str) {
         match self {
             Shell::Bash => (r"\[", r"\]"),
+            Shell::Elvish => (r"", r""),
             Shell::Fish => (r"", r""),
+            Shell::Nu => (r"", r""),
+            Shell::Pwsh => (r"", r""),
+            Shell::Xonsh => (r"", r""),
             Shell::Zsh => (r"%{", r"%}"),
         }
     }
 
+    fn string_terminator(&self) -> &'static str {
+        match self {
+            // The backslash of ESC \ would escape the closing \] of the prompt.
+            Shell::Bash => "\x07",
+            Shell::Elvish => "\x1b\\",
+            Shell::Fish => "\x1b\\",
+            Shell::Nu => "\x1b\\",
+            Shell::Pwsh => "\x1b\\",
+            Shell::Xonsh => "\x1b\\",
+            Shell::Zsh => "\x1b\\",
+        }
+    }
+
     pub fn escape<'a>(&self, s: &'a str) -> Cow<'a, str> {
         match self {
             Shell::Bash => Cow::from(s.replace('\\', r"\\")),
+            Shell::Elvish => Cow::from(s),
             Shell::Fish => Cow::from(s),
+            Shell::Nu => Cow::from(s),
+            Shell::Pwsh => Cow::from(s),
+            Shell::Xonsh => Cow::from(s.replace('{', "{{").replace('}', "}}")),
             Shell::Zsh => Cow::from(s.replace('%', "%%")),
         }
     }
@@ -49,6 +79,28 @@ impl<C: fmt::Display> fmt::Display for PromptControl<'_, C> {
     }
 }
 
+/// An operating system command sequence such as a hyperlink or a semantic prompt mark.
+#[derive(Debug)]
+pub struct Osc<C: fmt::Display> {
+    command: C,
+    terminator: &'static str,
+}
+
+impl<C: fmt::Display> Osc<C> {
+    fn new(command: C, terminator: &'static str) -> Self {
+        Self {
+            command,
+            terminator,
+        }
+    }
+}
+
+impl<C: fmt::Display> fmt::Display for Osc<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\x1b]{}{}", self.command, self.terminator)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,7 +111,32 @@ mod tests {
             &format!("{}", Shell::Bash.control("\x1b[m")),
             "\\[\x1b[m\\]"
         );
+        assert_eq!(&format!("{}", Shell::Elvish.control("\x1b[m")), "\x1b[m");
         assert_eq!(&format!("{}", Shell::Fish.control("\x1b[m")), "\x1b[m");
+        assert_eq!(&format!("{}", Shell::Nu.control("\x1b[m")), "\x1b[m");
+        assert_eq!(&format!("{}", Shell::Pwsh.control("\x1b[m")), "\x1b[m");
+        assert_eq!(&format!("{}", Shell::Xonsh.control("\x1b[m")), "\x1b[m");
         assert_eq!(&format!("{}", Shell::Zsh.control("\x1b[m")), "%{\x1b[m%}");
     }
+
+    #[test]
+    fn test_osc() {
+        assert_eq!(
+            &format!("{}", Shell::Bash.osc("133;A")),
+            "\\[\x1b]133;A\x07\\]"
+        );
+        assert_eq!(&format!("{}", Shell::Fish.osc("133;A")), "\x1b]133;A\x1b\\");
+        assert_eq!(
+            &format!("{}", Shell::Zsh.osc("133;A")),
+            "%{\x1b]133;A\x1b\\%}"
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(Shell::Bash.escape(r"\w{x}%"), r"\\w{x}%");
+        assert_eq!(Shell::Elvish.escape(r"\w{x}%"), r"\w{x}%");
+        assert_eq!(Shell::Xonsh.escape(r"\w{x}%"), r"\w{{x}}%");
+        assert_eq!(Shell::Zsh.escape(r"\w{x}%"), r"\w{x}%%");
+    }
 }
@@ -1,3 +1,6 @@
+// Each test crate uses only some of the helpers, and the helpers pass their arguments by reference.
+#![allow(dead_code, clippy::needless_borrows_for_generic_args)]
+
 use std::{
     fs::canonicalize,
     path::PathBuf,
@@ -130,4 +133,108 @@ impl GitRepo {
             );
         }
     }
+
+    pub fn commit_at(&self, message: &str, date: &str) {
+        let output = self
+            .git()
+            .args(&["commit", "-m", message])
+            .env("GIT_AUTHOR_DATE", date)
+            .env("GIT_COMMITTER_DATE", date)
+            .output()
+            .unwrap();
+        if !output.status.success() {
+            panic!(
+                "Failed to commit: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    pub fn stash(&self) {
+        let output = self.git().arg("stash").output().unwrap();
+        if !output.status.success() {
+            panic!(
+                "Failed to stash: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    pub fn switch_create(&self, branch: &str) {
+        let output = self.git().args(&["switch", "-c", branch]).output().unwrap();
+        if !output.status.success() {
+            panic!(
+                "Failed to switch to {}: {}",
+                branch,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    pub fn remote_add(&self, name: &str, url: &str) {
+        let output = self
+            .git()
+            .args(&["remote", "add", name, url])
+            .output()
+            .unwrap();
+        if !output.status.success() {
+            panic!(
+                "Failed to add remote {}: {}",
+                name,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    pub fn update_ref(&self, reference: &str, target: &str) {
+        let output = self
+            .git()
+            .args(&["update-ref", reference, target])
+            .output()
+            .unwrap();
+        if !output.status.success() {
+            panic!(
+                "Failed to update {}: {}",
+                reference,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    pub fn worktree_add(&self, path: &str, branch: &str) {
+        let output = self
+            .git()
+            .args(&["worktree", "add", "-b", branch, path])
+            .output()
+            .unwrap();
+        if !output.status.success() {
+            panic!(
+                "Failed to add worktree {}: {}",
+                path,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    pub fn submodule_add(&self, url: &str, path: &str) {
+        let output = self
+            .git()
+            .args(&[
+                "-c",
+                "protocol.file.allow=always",
+                "submodule",
+                "add",
+                url,
+                path,
+            ])
+            .output()
+            .unwrap();
+        if !output.status.success() {
+            panic!(
+                "Failed to add submodule {}: {}",
+                path,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
 }
@@ -1,6 +1,8 @@
+#![allow(clippy::needless_borrows_for_generic_args)]
+
 mod helpers;
 
-use std::process::Command;
+use std::{path::PathBuf, process::Command};
 
 use crate::helpers::TestEnv;
 
@@ -29,12 +31,26 @@ fn test_init_bash() {
     let stdout = run_init(env, "bash");
 
     assert!(
-        stdout.contains(r#"PS1="$(croque prompt"#),
+        stdout.contains(r#""$(croque prompt --exit-status="#),
         "stdout: {stdout}"
     );
     assert!(stdout.contains(r#"croque::precmd() {"#), "stdout: {stdout}");
     assert!(
-        stdout.contains(r#"PROMPT_COMMAND=croque::precmd"#),
+        stdout.contains(r#"croque prepare "$source""#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(
+            r#"PS2='${__croque_prompt_active:0:$((__croque_prompt_active=0,0))}'"$(croque prompt --continuation --exit-status="#
+        ),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"trap croque::prepare-async::callback WINCH"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"PROMPT_COMMAND="croque::precmd${PROMPT_COMMAND:+;$PROMPT_COMMAND}""#),
         "stdout: {stdout}"
     );
 
@@ -42,6 +58,64 @@ fn test_init_bash() {
     insta::assert_snapshot!("init_bash", stdout);
 }
 
+#[test]
+fn test_init_bash_redraw() {
+    let env = &TestEnv::new();
+    let stdout = run_init(env, "bash");
+
+    // The prompt markers only appear in an interactive shell, which reports on stderr that it has no job control.
+    let script = format!(
+        r#"{stdout}
+COLUMNS=10
+PS1='${{__croque_prompt_active:0:$((__croque_prompt_active=1,0))}}\[\e[1m\]0123456789abcde\[\e[m\]'$'\n''\[\e]8;;file:///a\\b\a\]x\\'
+croque::prompt-rows
+echo "$__croque_prompt_rows $__croque_prompt_active"
+PROMPT_COMMAND='croque::precmd;echo user'
+__croque_prompt_active=1
+croque::redraw
+echo "${{PROMPT_COMMAND[*]}}"
+croque::precmd
+echo "${{PROMPT_COMMAND[*]}}"
+"#
+    );
+    let output = Command::new("bash")
+        .args(&["--norc", "-i", "-c", &script])
+        .current_dir(env.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "3 0\ncroque::precmd\n\x1b[3A\r\x1b[Jcroque::precmd;echo user\n"
+    );
+}
+
+#[test]
+fn test_init_elvish() {
+    let env = &TestEnv::new();
+    let stdout = run_init(env, "elvish");
+
+    assert!(
+        stdout.contains(r#"set edit:after-command = "#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"croque prompt --exit-status=$croque-exit-status"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"croque prompt --right --exit-status=$croque-exit-status"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"set edit:before-readline = "#),
+        "stdout: {stdout}"
+    );
+
+    assert_eq!(run_shell(env, "elvish", &stdout), "");
+    insta::assert_snapshot!("init_elvish", stdout);
+}
+
 #[test]
 fn test_init_fish() {
     let env = &TestEnv::new();
@@ -63,11 +137,101 @@ fn test_init_fish() {
         stdout.contains(r#"croque prompt --right --exit-status"#),
         "stdout: {stdout}"
     );
+    assert!(
+        stdout.contains(r#"croque prepare $source"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"commandline -f repaint"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"croque prompt --transient --exit-status"#),
+        "stdout: {stdout}"
+    );
 
     assert_eq!(run_shell(env, "fish", &stdout), "");
     insta::assert_snapshot!("init_fish", stdout);
 }
 
+#[test]
+fn test_init_nu() {
+    let env = &TestEnv::new();
+    let stdout = run_init(env, "nu");
+
+    assert!(
+        stdout.contains(r#"$env.PROMPT_COMMAND = {|| croque-prompt }"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"$env.PROMPT_COMMAND_RIGHT = {|| croque-prompt --right }"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"$env.LAST_EXIT_CODE"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"$env.CMD_DURATION_MS"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"append {|| croque-prepare }"#),
+        "stdout: {stdout}"
+    );
+
+    assert_eq!(run_shell(env, "nu", &stdout), "");
+    insta::assert_snapshot!("init_nu", stdout);
+}
+
+#[test]
+fn test_init_powershell() {
+    let env = &TestEnv::new();
+    let stdout = run_init(env, "powershell");
+
+    assert!(
+        stdout.contains(r#"function global:prompt {"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"& croque prompt "--exit-status=$exitStatus""#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"$Host.UI.RawUI.WindowSize.Width"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"(& croque prepare $source) -join """#),
+        "stdout: {stdout}"
+    );
+
+    assert_eq!(run_shell(env, "pwsh", &stdout), "");
+    insta::assert_snapshot!("init_powershell", stdout);
+}
+
+#[test]
+fn test_init_xonsh() {
+    let env = &TestEnv::new();
+    let stdout = run_init(env, "xonsh");
+
+    assert!(
+        stdout.contains(r#"$PROMPT = lambda: __croque_prompt()"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"$RIGHT_PROMPT = lambda: __croque_prompt(right=True)"#),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#"@events.on_pre_prompt"#),
+        "stdout: {stdout}"
+    );
+
+    assert_eq!(run_shell(env, "xonsh", &stdout), "");
+    insta::assert_snapshot!("init_xonsh", stdout);
+}
+
 #[test]
 fn test_init_zsh() {
     let env = &TestEnv::new();
@@ -97,11 +261,11 @@ fn test_init_zsh() {
         "stdout: {stdout}"
     );
     assert!(
-        stdout.contains(r#"croque prompt --exit-status="#),
+        stdout.contains(r#"croque prompt "$@" --exit-status="#),
         "stdout: {stdout}"
     );
     assert!(
-        stdout.contains(r#"croque prompt --right --exit-status="#),
+        stdout.contains(r#"croque prompt --right "$@" --exit-status="#),
         "stdout: {stdout}"
     );
     assert!(
@@ -112,6 +276,10 @@ fn test_init_zsh() {
         stdout.contains(r#"PROMPT='$(croque::prompt)'"#),
         "stdout: {stdout}"
     );
+    assert!(
+        stdout.contains(r#"PROMPT2='$(croque::prompt --continuation)'"#),
+        "stdout: {stdout}"
+    );
     assert!(
         stdout.contains(r#"RPROMPT='$(croque::rprompt)'"#),
         "stdout: {stdout}"
@@ -120,3 +288,66 @@ fn test_init_zsh() {
     assert_eq!(run_shell(env, "zsh", &stdout), "");
     insta::assert_snapshot!("init_zsh", stdout);
 }
+
+#[test]
+fn test_init_semantic_prompt() {
+    let env = &TestEnv::new();
+    let config = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/config/semantic_prompt.yaml");
+
+    for (shell, expected) in [
+        ("bash", r#"PS0="${PS0}"'\e]133;C\a"#),
+        (
+            "fish",
+            r#"function __croque_semantic_prompt_postexec --on-event fish_postexec"#,
+        ),
+        (
+            "zsh",
+            r#"add-zsh-hook precmd croque::semantic-prompt::precmd"#,
+        ),
+    ] {
+        let output = env
+            .command(".")
+            .args(&["init", shell])
+            .env("CROQUE_CONFIG_FILE", &config)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        assert!(output.status.success());
+        assert!(stdout.contains(expected), "stdout: {stdout}");
+
+        let stdout = run_init(env, shell);
+        assert!(!stdout.contains(expected), "stdout: {stdout}");
+    }
+}
+
+#[test]
+fn test_init_transient() {
+    let env = &TestEnv::new();
+    let config =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config/transient.yaml");
+
+    for (shell, expected) in [
+        (
+            "fish",
+            r#"bind -M $__croque_mode \r __croque_transient_execute"#,
+        ),
+        (
+            "zsh",
+            r#"add-zle-hook-widget line-finish croque::line-finish"#,
+        ),
+    ] {
+        let output = env
+            .command(".")
+            .args(&["init", shell])
+            .env("CROQUE_CONFIG_FILE", &config)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        assert!(output.status.success());
+        assert!(stdout.contains(expected), "stdout: {stdout}");
+
+        let stdout = run_init(env, shell);
+        assert!(!stdout.contains(expected), "stdout: {stdout}");
+    }
+}
@@ -1,10 +1,15 @@
-use std::path::PathBuf;
+#![allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
+
+use std::{
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+};
 
 use crate::helpers::TestEnv;
 
 mod helpers;
 
-const SHELLS: &[&str] = &["bash", "fish", "zsh"];
+const SHELLS: &[&str] = &["bash", "elvish", "fish", "xonsh", "zsh"];
 
 struct PromptInput {
     config: PathBuf,
@@ -15,6 +20,7 @@ struct PromptInput {
     data_git: Option<String>,
     data_gh: Option<String>,
     data_glab: Option<String>,
+    path: Option<PathBuf>,
 }
 
 impl PromptInput {
@@ -29,6 +35,7 @@ impl PromptInput {
             data_git: None,
             data_gh: None,
             data_glab: None,
+            path: None,
         }
     }
 
@@ -39,6 +46,12 @@ impl PromptInput {
         self
     }
 
+    fn fixture_config(&mut self, name: &str) -> &mut Self {
+        self.config = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join(format!("tests/fixtures/config/{name}.yaml"));
+        self
+    }
+
     fn exit_status(&mut self, exit_status: i32) -> &mut Self {
         self.exit_status = exit_status;
         self
@@ -68,6 +81,11 @@ impl PromptInput {
         self.data_glab = Some(data_glab.to_string());
         self
     }
+
+    fn path(&mut self, path: &Path) -> &mut Self {
+        self.path = Some(path.to_path_buf());
+        self
+    }
 }
 
 fn run_prompt(env: &TestEnv, shell: &str, input: &PromptInput, dir: &str) -> String {
@@ -93,6 +111,9 @@ fn run_prompt(env: &TestEnv, shell: &str, input: &PromptInput, dir: &str) -> Str
     if let Some(data_glab) = &input.data_glab {
         cmd.args(&["--data.glab", data_glab]);
     }
+    if let Some(path) = &input.path {
+        cmd.env("PATH", path);
+    }
 
     let output = cmd
         .env("HOME", env.path())
@@ -123,6 +144,17 @@ fn run_prepare(env: &TestEnv, source: &str, dir: &str) -> String {
     stdout.trim().to_string()
 }
 
+// Installs a command that only leaves a file behind, and returns the directory to put in PATH and the file.
+fn fake_command(env: &TestEnv, name: &str) -> (PathBuf, PathBuf) {
+    let bin = env.path().join("bin");
+    let ran = env.path().join(format!("{name}.ran"));
+    let command = bin.join(name);
+    std::fs::create_dir_all(&bin).unwrap();
+    std::fs::write(&command, format!("#!/bin/sh\n: >'{}'\n", ran.display())).unwrap();
+    std::fs::set_permissions(&command, std::fs::Permissions::from_mode(0o755)).unwrap();
+    (bin, ran)
+}
+
 mod direnv {
     // TODO
 }
@@ -216,7 +248,163 @@ mod gh_actions {
 }
 
 mod gh_pull_request {
-    // TODO
+    use super::*;
+
+    #[test]
+    fn not_configured() {
+        let env = &TestEnv::new();
+        let (bin, ran) = fake_command(env, "gh");
+
+        for shell in SHELLS {
+            run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("git_commit").path(&bin),
+                ".",
+            );
+        }
+        assert!(!ran.exists());
+    }
+
+    #[test]
+    fn configured() {
+        let env = &TestEnv::new();
+        let (bin, ran) = fake_command(env, "gh");
+
+        run_prompt(env, "bash", &PromptInput::new().path(&bin).data_gh(""), ".");
+        assert!(!ran.exists());
+
+        run_prompt(env, "bash", &PromptInput::new().path(&bin), ".");
+        assert!(ran.exists());
+    }
+}
+
+mod git_commit {
+    use super::*;
+
+    const FRESH_STYLE: &str = "\x1b[48;5;250;30m";
+    const STALE_STYLE: &str = "\x1b[48;5;214;30m";
+
+    #[test]
+    fn fresh() {
+        let env = &TestEnv::new();
+        let git = env.git(".");
+
+        git.init("main");
+        git.config_set("user.name", "John Doe");
+        git.config_set("user.email", "john.doe@example.com");
+        env.write_file("README", "");
+        git.add(&["README"]);
+        git.commit("Initial commit");
+
+        for shell in SHELLS {
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("git_commit"),
+                ".",
+            );
+
+            assert!(output.contains(" just now by John Doe "), "{output}");
+            assert!(output.contains(FRESH_STYLE), "{output}");
+        }
+    }
+
+    #[test]
+    fn stale() {
+        let env = &TestEnv::new();
+        let git = env.git(".");
+
+        git.init("main");
+        git.config_set("user.name", "John Doe");
+        git.config_set("user.email", "john.doe@example.com");
+        env.write_file("README", "");
+        git.add(&["README"]);
+        git.commit_at("Initial commit", "2000-01-01T00:00:00Z");
+
+        for shell in SHELLS {
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("git_commit"),
+                ".",
+            );
+
+            assert!(output.contains("y ago by John Doe "), "{output}");
+            assert!(output.contains(STALE_STYLE), "{output}");
+        }
+    }
+}
+
+mod git_diff {
+    use super::*;
+
+    fn setup_repo(env: &TestEnv) {
+        let git = env.git(".");
+
+        git.init("main");
+        git.config_set("user.name", "John Doe");
+        git.config_set("user.email", "john.doe@example.com");
+        env.write_file("README", "one\ntwo\n");
+        git.add(&["README"]);
+        git.commit("Initial commit");
+        env.write_file("README", "one\nthree\nfour\n");
+    }
+
+    #[test]
+    fn not_configured() {
+        let env = &TestEnv::new();
+        setup_repo(env);
+
+        for shell in SHELLS {
+            let output = run_prompt(env, shell, &PromptInput::new(), ".");
+
+            assert!(!output.contains(" +2 −1 "), "{output}");
+        }
+    }
+
+    #[test]
+    fn configured() {
+        let env = &TestEnv::new();
+        setup_repo(env);
+
+        for shell in SHELLS {
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("git_diff"),
+                ".",
+            );
+
+            assert!(output.contains(" +2 −1 "), "{output}");
+        }
+    }
+
+    #[test]
+    fn prepare() {
+        let env = &TestEnv::new();
+        setup_repo(env);
+
+        let mut input = PromptInput::new();
+        input.fixture_config("git_diff");
+        let output = env
+            .command(".")
+            .args(&["prepare", "git"])
+            .env("CROQUE_CONFIG_FILE", &input.config)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        input.data_git(String::from_utf8_lossy(&output.stdout).trim());
+
+        // Changes made after the prepare must not show up.
+        env.write_file("README", "");
+
+        for shell in SHELLS {
+            let output = run_prompt(env, shell, &input, ".");
+
+            assert!(output.contains(" +2 −1 "), "{output}");
+        }
+    }
 }
 
 mod git_status {
@@ -350,6 +538,208 @@ mod git_status {
         }
     }
 
+    #[test]
+    fn base_branch() {
+        let env = &TestEnv::new();
+        let git = env.git(".");
+
+        git.init("main");
+        git.config_set("user.name", "John Doe");
+        git.config_set("user.email", "john.doe@example.com");
+        env.write_file("README", "");
+        git.add(&["README"]);
+        git.commit("Initial commit");
+        git.switch_create("feature");
+        env.write_file("README", "1");
+        git.add(&["README"]);
+        git.commit("Commit 1");
+        env.write_file("README", "2");
+        git.add(&["README"]);
+        git.commit("Commit 2");
+
+        for shell in SHELLS {
+            let output = run_prompt(env, shell, &PromptInput::new(), ".");
+
+            assert!(!output.contains(" ↑2"), "{output}");
+
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("base_branch"),
+                ".",
+            );
+
+            assert_git_status_segment_branch(&output, "feature");
+            assert!(output.contains(" ↑2 "), "{output}");
+        }
+    }
+
+    #[test]
+    fn worktree() {
+        let env = &TestEnv::new();
+        std::fs::create_dir(env.path().join("repo")).unwrap();
+        let git = env.git("repo");
+
+        git.init("main");
+        git.config_set("user.name", "John Doe");
+        git.config_set("user.email", "john.doe@example.com");
+        env.write_file("repo/README", "");
+        git.add(&["README"]);
+        git.commit("Initial commit");
+        git.worktree_add("../feature-wt", "feature");
+
+        for shell in SHELLS {
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("worktree"),
+                "repo",
+            );
+
+            assert_git_status_segment_branch(&output, "main");
+            assert!(!output.contains(" ⊞"), "{output}");
+
+            let output = run_prompt(env, shell, &PromptInput::new(), "feature-wt");
+
+            assert_git_status_segment_branch(&output, "feature");
+            assert!(!output.contains(" ⊞"), "{output}");
+
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("worktree"),
+                "feature-wt",
+            );
+
+            assert_git_status_segment_branch(&output, "feature");
+            assert!(output.contains(" ⊞feature-wt "), "{output}");
+        }
+    }
+
+    #[test]
+    fn submodule() {
+        let env = &TestEnv::new();
+        for dir in ["app", "library"] {
+            std::fs::create_dir(env.path().join(dir)).unwrap();
+            let git = env.git(dir);
+
+            git.init("main");
+            git.config_set("user.name", "John Doe");
+            git.config_set("user.email", "john.doe@example.com");
+            env.write_file(&format!("{dir}/README"), "");
+            git.add(&["README"]);
+            git.commit("Initial commit");
+        }
+        let library_url = env.path().join("library");
+        env.git("app")
+            .submodule_add(library_url.to_str().unwrap(), "libs/library");
+        std::fs::create_dir(env.path().join("app/libs/library/src")).unwrap();
+
+        for shell in SHELLS {
+            let output = run_prompt(env, shell, &PromptInput::new(), "app/libs/library/src");
+
+            assert_git_status_segment_branch(&output, "main");
+            assert!(!output.contains(" ⊂"), "{output}");
+            assert!(output.contains(" ~/app/l/library/src "), "{output}");
+
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("submodule"),
+                "app/libs/library/src",
+            );
+
+            assert_git_status_segment_branch(&output, "main");
+            assert!(output.contains(" ⊂app "), "{output}");
+        }
+    }
+
+    #[test]
+    fn lfs_and_sparse_checkout() {
+        let env = &TestEnv::new();
+        let git = env.git(".");
+
+        git.init("main");
+        git.config_set("user.name", "John Doe");
+        git.config_set("user.email", "john.doe@example.com");
+        env.write_file(
+            ".gitattributes",
+            "*.bin filter=lfs diff=lfs merge=lfs -text\n",
+        );
+        env.write_file(
+            "data.bin",
+            "version https://git-lfs.github.com/spec/v1\n\
+             oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393\n\
+             size 12345\n",
+        );
+        git.add(&[".gitattributes", "data.bin"]);
+        git.commit("Initial commit");
+
+        for shell in SHELLS {
+            let output = run_prompt(env, shell, &PromptInput::new(), ".");
+
+            assert_git_status_segment_branch(&output, "main");
+            assert!(!output.contains(" ⛁"), "{output}");
+
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("lfs_and_sparse_checkout"),
+                ".",
+            );
+
+            assert_git_status_segment_branch(&output, "main");
+            assert!(output.contains(" ⛁! "), "{output}");
+            assert!(!output.contains(" ◐"), "{output}");
+        }
+
+        env.write_file("data.bin", &"0".repeat(12345));
+        git.config_set("core.sparseCheckout", "true");
+
+        for shell in SHELLS {
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("lfs_and_sparse_checkout"),
+                ".",
+            );
+
+            assert!(output.contains(" ⛁ ◐ "), "{output}");
+            assert!(!output.contains(" ⛁!"), "{output}");
+        }
+    }
+
+    #[test]
+    fn working_tree_counts() {
+        let env = &TestEnv::new();
+        let git = env.git(".");
+
+        git.init("main");
+        git.config_set("user.name", "John Doe");
+        git.config_set("user.email", "john.doe@example.com");
+        env.write_file("README", "readme");
+        env.write_file("LICENSE", "license");
+        git.add(&["README", "LICENSE"]);
+        git.commit("Initial commit");
+        env.write_file("README", "modified");
+        env.write_file("LICENSE", "modified");
+        git.add(&["LICENSE"]);
+        env.write_file("NEW_1", "new 1");
+        env.write_file("NEW_2", "new 2");
+
+        for shell in SHELLS {
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("working_tree_counts"),
+                ".",
+            );
+
+            assert_git_status_segment_branch(&output, "main");
+            assert!(output.contains(" ~1 | ?2 ✚1 "), "{output}");
+        }
+    }
+
     #[test]
     fn snapshot() {
         fn setup_none(_env: &TestEnv, _cfg: &mut PromptInput) {}
@@ -404,6 +794,65 @@ mod git_status {
             env.write_file("README", "modified");
         }
 
+        fn setup_stash(env: &TestEnv, _cfg: &mut PromptInput) {
+            let git = env.git(".");
+
+            git.init("main");
+            git.config_set("user.name", "John Doe");
+            git.config_set("user.email", "john.doe@example.com");
+            env.write_file("README", "");
+            git.add(&["README"]);
+            git.commit("Initial commit");
+            env.write_file("README", "stashed 1");
+            git.stash();
+            env.write_file("README", "stashed 2");
+            git.stash();
+        }
+
+        fn setup_no_upstream(env: &TestEnv, _cfg: &mut PromptInput) {
+            let git = env.git(".");
+
+            git.init("main");
+            git.config_set("user.name", "John Doe");
+            git.config_set("user.email", "john.doe@example.com");
+            env.write_file("README", "");
+            git.add(&["README"]);
+            git.commit("Initial commit");
+            git.remote_add("origin", "https://example.com/repo.git");
+        }
+
+        fn setup_gone_upstream(env: &TestEnv, _cfg: &mut PromptInput) {
+            let git = env.git(".");
+
+            git.init("main");
+            git.config_set("user.name", "John Doe");
+            git.config_set("user.email", "john.doe@example.com");
+            env.write_file("README", "");
+            git.add(&["README"]);
+            git.commit("Initial commit");
+            git.remote_add("origin", "https://example.com/repo.git");
+            git.config_set("branch.main.remote", "origin");
+            git.config_set("branch.main.merge", "refs/heads/main");
+        }
+
+        fn setup_tracking_upstream(env: &TestEnv, _cfg: &mut PromptInput) {
+            let git = env.git(".");
+
+            git.init("main");
+            git.config_set("user.name", "John Doe");
+            git.config_set("user.email", "john.doe@example.com");
+            env.write_file("README", "");
+            git.add(&["README"]);
+            git.commit("Initial commit");
+            git.remote_add("origin", "https://example.com/repo.git");
+            git.config_set("branch.main.remote", "origin");
+            git.config_set("branch.main.merge", "refs/heads/main");
+            git.update_ref("refs/remotes/origin/main", "HEAD");
+            env.write_file("README", "modified");
+            git.add(&["README"]);
+            git.commit("Second commit");
+        }
+
         for shell in SHELLS {
             type SetupFn = fn(&TestEnv, &mut PromptInput);
             for (testname, setup) in [
@@ -414,6 +863,10 @@ mod git_status {
                 ("stage_file", setup_stage_file as SetupFn),
                 ("commit_file", setup_commit_file as SetupFn),
                 ("modify_file", setup_modify_file as SetupFn),
+                ("stash", setup_stash as SetupFn),
+                ("no_upstream", setup_no_upstream as SetupFn),
+                ("gone_upstream", setup_gone_upstream as SetupFn),
+                ("tracking_upstream", setup_tracking_upstream as SetupFn),
             ] {
                 let env = &TestEnv::new();
 
@@ -580,7 +1033,108 @@ mod git_user {
 }
 
 mod glab_merge_request {
-    // TODO
+    use super::*;
+
+    #[test]
+    fn not_configured() {
+        let env = &TestEnv::new();
+        let (bin, ran) = fake_command(env, "glab");
+
+        for shell in SHELLS {
+            run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("git_commit").path(&bin),
+                ".",
+            );
+        }
+        assert!(!ran.exists());
+    }
+
+    #[test]
+    fn configured() {
+        let env = &TestEnv::new();
+        let (bin, ran) = fake_command(env, "glab");
+
+        run_prompt(
+            env,
+            "bash",
+            &PromptInput::new().path(&bin).data_glab(""),
+            ".",
+        );
+        assert!(!ran.exists());
+
+        run_prompt(env, "bash", &PromptInput::new().path(&bin), ".");
+        assert!(ran.exists());
+    }
+}
+
+mod hyperlinks {
+    use super::*;
+
+    const LINK_END: &str = "\x1b]8;;";
+
+    fn string_terminator(shell: &str) -> &'static str {
+        match shell {
+            "bash" => "\x07",
+            _ => "\x1b\\",
+        }
+    }
+
+    #[test]
+    fn disabled() {
+        let env = &TestEnv::new();
+
+        for shell in SHELLS {
+            let output = run_prompt(env, shell, &PromptInput::new(), ".");
+
+            assert!(!output.contains(LINK_END), "{output}");
+        }
+    }
+
+    #[test]
+    fn path() {
+        let env = &TestEnv::new();
+        let link_start = format!(
+            "\x1b]8;;file://{}",
+            hostname::get().unwrap().to_string_lossy()
+        );
+
+        for shell in SHELLS {
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("hyperlinks"),
+                ".",
+            );
+
+            let terminator = string_terminator(shell);
+            assert!(output.contains(&link_start), "{output}");
+            assert!(
+                output.contains(&format!("{LINK_END}{terminator}")),
+                "{output}"
+            );
+        }
+    }
+
+    #[test]
+    fn zsh_percent_encoded_path() {
+        let env = &TestEnv::new();
+        std::fs::create_dir_all(env.path().join("rev dir/今")).unwrap();
+
+        let output = run_prompt(
+            env,
+            "zsh",
+            &PromptInput::new().fixture_config("hyperlinks"),
+            "rev dir/今",
+        );
+
+        // A lone `%` would start a zsh prompt escape such as `%E` or `%B`.
+        assert!(
+            output.contains("/rev%%20dir/%%E4%%BB%%8A\x1b\\%}"),
+            "{output}"
+        );
+    }
 }
 
 mod os {
@@ -591,6 +1145,52 @@ mod path {
     // TODO
 }
 
+mod semantic_prompt {
+    use super::*;
+
+    const PROMPT_START: &str = "\x1b]133;A";
+    const INPUT_START: &str = "\x1b]133;B";
+
+    #[test]
+    fn disabled() {
+        let env = &TestEnv::new();
+
+        for shell in SHELLS {
+            let output = run_prompt(env, shell, &PromptInput::new(), ".");
+
+            assert!(!output.contains(PROMPT_START), "{output}");
+            assert!(!output.contains(INPUT_START), "{output}");
+        }
+    }
+
+    #[test]
+    fn enabled() {
+        let env = &TestEnv::new();
+
+        for (shell, prefix, terminator, suffix) in [
+            ("bash", r"\[", "\x07", r"\]"),
+            ("fish", "", "\x1b\\", ""),
+            ("zsh", "%{", "\x1b\\", "%}"),
+        ] {
+            let output = run_prompt(
+                env,
+                shell,
+                &PromptInput::new().fixture_config("semantic_prompt"),
+                ".",
+            );
+
+            assert!(
+                output.starts_with(&format!("{prefix}{PROMPT_START}{terminator}{suffix}")),
+                "{output}"
+            );
+            assert!(
+                output.ends_with(&format!("{prefix}{INPUT_START}{terminator}{suffix}")),
+                "{output}"
+            );
+        }
+    }
+}
+
 mod status {
     use super::*;
 
@@ -747,6 +1347,68 @@ mod time {
     }
 }
 
+mod title {
+    use super::*;
+
+    const TITLE_START: &str = "\x1b]0;";
+    const CWD_START: &str = "\x1b]7;file://";
+
+    #[test]
+    fn disabled() {
+        let env = &TestEnv::new();
+
+        for shell in SHELLS {
+            let output = run_prompt(env, shell, &PromptInput::new(), ".");
+
+            assert!(!output.contains(TITLE_START), "{output}");
+            assert!(!output.contains(CWD_START), "{output}");
+        }
+    }
+
+    #[test]
+    fn enabled() {
+        let env = &TestEnv::new();
+
+        for (shell, prefix, terminator, suffix) in [
+            ("bash", r"\[", "\x07", r"\]"),
+            ("fish", "", "\x1b\\", ""),
+            ("zsh", "%{", "\x1b\\", "%}"),
+        ] {
+            let output = run_prompt(env, shell, &PromptInput::new().fixture_config("title"), ".");
+
+            let title = format!("{prefix}{TITLE_START}croque{terminator}{suffix}");
+            assert!(output.starts_with(&title), "{output}");
+
+            let cwd = &output[title.len()..];
+            assert!(cwd.starts_with(&format!("{prefix}{CWD_START}")), "{output}");
+            let cwd_end = cwd.find(terminator).unwrap();
+            assert!(
+                cwd[cwd_end..].starts_with(&format!("{terminator}{suffix}")),
+                "{output}"
+            );
+        }
+    }
+
+    #[test]
+    fn zsh_percent_encoded_cwd() {
+        let env = &TestEnv::new();
+        std::fs::create_dir_all(env.path().join("rev dir/今")).unwrap();
+
+        let output = run_prompt(
+            env,
+            "zsh",
+            &PromptInput::new().fixture_config("title"),
+            "rev dir/今",
+        );
+
+        // A lone `%` would start a zsh prompt escape such as `%E` or `%B`.
+        assert!(
+            output.contains("/rev%%20dir/%%E4%%BB%%8A\x1b\\%}"),
+            "{output}"
+        );
+    }
+}
+
 mod user {
     use super::*;
 